@@ -0,0 +1,43 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Generates the solution registry from the day binaries in `src/bin`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<(u8, String)> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            let is_day = stem.len() == 2 && path.extension()? == "rs";
+            let day = stem.parse::<u8>().ok().filter(|_| is_day)?;
+            Some((day, path.to_string_lossy().to_string()))
+        })
+        .collect();
+
+    days.sort();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (day, path) in days {
+        modules.push_str(&format!(
+            "#[cfg(not(test))]\n#[path = {:?}]\nmod day{:02};\n",
+            path, day
+        ));
+        entries.push_str(&format!("    Day::new::<day{0:02}::Day{0:02}>(),\n", day));
+    }
+
+    let generated = format!(
+        "{}\n#[cfg(not(test))]\npub static DAYS: &[Day] = &[\n{}];\n\n#[cfg(test)]\npub static DAYS: &[Day] = &[];\n",
+        modules, entries
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, generated).unwrap();
+}
@@ -0,0 +1,209 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Accepted answers stored in `answers.json`, used to catch regressions.
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tinyjson::JsonValue;
+
+use crate::report::PartResult;
+use crate::{ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+pub const ANSWERS_FILE: &str = "answers.json";
+
+/// Location of the answers file in the project directory.
+pub fn answers_path() -> PathBuf {
+    crate::project_dir().join(ANSWERS_FILE)
+}
+
+/// How a computed answer compares to the accepted one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// No answer has been accepted for this part yet.
+    Unknown,
+    Correct,
+    /// The answer differs from the accepted one, which is included.
+    Incorrect(String),
+}
+
+impl Verdict {
+    /// Suffix appended to an answer when printing it.
+    pub fn mark(&self) -> String {
+        match self {
+            Verdict::Unknown => "".into(),
+            Verdict::Correct => format!(" {}✓{}", ANSI_GREEN, ANSI_RESET),
+            Verdict::Incorrect(expected) => {
+                format!(" {}✗ (expected: {}){}", ANSI_RED, expected, ANSI_RESET)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// Loads answers from `path`. A missing file yields an empty store.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("could not read \"{}\": {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_json())
+            .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    /// Compares a result to the accepted answer of its part.
+    pub fn check(&self, result: &PartResult) -> Verdict {
+        self.check_answer(result.day, result.part, result.answer.as_deref())
+    }
+
+    /// Compares an answer to the accepted one.
+    /// An unsolved part (`None`) is incorrect if an answer had been accepted before.
+    pub fn check_answer(&self, day: u8, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(day, part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Correct,
+            (Some(expected), _) => Verdict::Incorrect(expected.to_string()),
+        }
+    }
+
+    /// Accepts the answers of solved parts in `results` that have no accepted answer yet.
+    /// Returns the number of answers stored. An accepted answer is never replaced by a different one;
+    /// it has to be removed from the answers file first.
+    pub fn lock(&mut self, results: &[PartResult]) -> usize {
+        results
+            .iter()
+            .filter_map(|result| {
+                let answer = result.answer.as_ref()?;
+                if self.get(result.day, result.part).is_some() {
+                    return None;
+                }
+                self.set(result.day, result.part, answer);
+                Some(())
+            })
+            .count()
+    }
+
+    pub fn parse(contents: &str) -> Result<Answers, String> {
+        let invalid = || "answers file is not valid".to_string();
+
+        let value: JsonValue = contents.parse().map_err(|_| invalid())?;
+        let days: &HashMap<String, JsonValue> = value.get().ok_or_else(invalid)?;
+
+        let mut answers = Answers::default();
+
+        for (day, parts) in days {
+            let day: u8 = day.parse().map_err(|_| invalid())?;
+            let parts: &HashMap<String, JsonValue> = parts.get().ok_or_else(invalid)?;
+
+            for (part, answer) in parts {
+                let part: u8 = part.parse().map_err(|_| invalid())?;
+                let answer: &String = answer.get().ok_or_else(invalid)?;
+                answers.set(day, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut days: BTreeMap<u8, Vec<String>> = BTreeMap::new();
+
+        for ((day, part), answer) in &self.answers {
+            days.entry(*day).or_default().push(format!(
+                "\"{}\": {}",
+                part,
+                JsonValue::String(answer.clone()).stringify().unwrap()
+            ));
+        }
+
+        let days = days
+            .iter()
+            .map(|(day, parts)| format!("  \"{:02}\": {{ {} }}", day, parts.join(", ")))
+            .collect::<Vec<String>>()
+            .join(",\n");
+
+        format!("{{\n{}\n}}\n", days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(1, 1, "24000");
+        answers.set(1, 2, "45000");
+        answers.set(10, 2, "##..\n..##");
+
+        let json = answers.to_json();
+        assert!(json.starts_with("{\n  \"01\": { \"1\": \"24000\", \"2\": \"45000\" },\n"));
+        assert_eq!(Answers::parse(&json), Ok(answers));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.set(6, 1, "7");
+
+        assert_eq!(
+            answers.check(&PartResult::new(6, 1, Some("7".into()), Duration::ZERO)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(&PartResult::new(6, 1, Some("8".into()), Duration::ZERO)),
+            Verdict::Incorrect("7".into())
+        );
+        assert_eq!(
+            answers.check(&PartResult::new(6, 1, None, Duration::ZERO)),
+            Verdict::Incorrect("7".into())
+        );
+        assert_eq!(
+            answers.check(&PartResult::new(6, 2, Some("19".into()), Duration::ZERO)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn test_lock() {
+        let mut answers = Answers::default();
+        let locked = answers.lock(&[
+            PartResult::new(6, 1, Some("7".into()), Duration::ZERO),
+            PartResult::new(6, 2, None, Duration::ZERO),
+        ]);
+
+        assert_eq!(locked, 1);
+        assert_eq!(answers.get(6, 1), Some("7"));
+        assert_eq!(answers.get(6, 2), None);
+
+        // accepted answers are kept, even if they differ.
+        let locked = answers.lock(&[
+            PartResult::new(6, 1, Some("8".into()), Duration::ZERO),
+            PartResult::new(6, 2, Some("9".into()), Duration::ZERO),
+        ]);
+        assert_eq!(locked, 1);
+        assert_eq!(answers.get(6, 1), Some("7"));
+        assert_eq!(answers.get(6, 2), Some("9"));
+    }
+}
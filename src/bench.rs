@@ -0,0 +1,222 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::answers::Verdict;
+use crate::panics;
+use crate::solution::{IntoOutcome, Solution};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+
+/// Default time spent sampling each part.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// Upper bound on samples per part, so very fast solutions do not collect unbounded data.
+const MAX_SAMPLES: usize = 100_000;
+
+/// Summary statistics over repeated runs of a solver.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    /// Computes statistics from individual sample timings. `samples` must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> BenchStats {
+        samples.sort();
+
+        let count = samples.len();
+        let mid = count / 2;
+        let median = if count % 2 == 1 {
+            samples[mid]
+        } else {
+            (samples[mid - 1] + samples[mid]) / 2
+        };
+
+        let nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count as f64;
+
+        BenchStats {
+            samples: count,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+
+    pub fn to_human(&self) -> String {
+        format!(
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, samples: {}",
+            self.min, self.median, self.mean, self.stddev, self.samples
+        )
+    }
+}
+
+/// Benchmark outcome for a single part. `stats` is `None` if the part is not solved.
+#[derive(Clone, Debug)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub stats: Option<BenchStats>,
+    /// Timings of parsing the input. Parsing is shared by both parts, so only the first part carries it.
+    pub parse: Option<BenchStats>,
+}
+
+impl BenchResult {
+    /// The parse timings are rendered in front of the part that carries them.
+    pub fn to_human(&self, verdict: &Verdict) -> String {
+        let header = match &self.parse {
+            Some(stats) => format!(
+                "🎄 {}Parse{} 🎄\n{}({}){}\n",
+                ANSI_BOLD,
+                ANSI_RESET,
+                ANSI_ITALIC,
+                stats.to_human(),
+                ANSI_RESET
+            ),
+            None => String::new(),
+        };
+        let header = format!(
+            "{}🎄 {}Part {}{} 🎄",
+            header, ANSI_BOLD, self.part, ANSI_RESET
+        );
+        if let Some(error) = &self.error {
+            return format!(
+                "{}\n{}Part {} failed: {}{}{}",
+                header,
+                ANSI_RED,
+                self.part,
+                error,
+                ANSI_RESET,
+                verdict.mark()
+            );
+        }
+
+        match (&self.answer, &self.stats) {
+            (Some(answer), Some(stats)) => format!(
+                "{}\n{}{} {}({}){}",
+                header,
+                answer,
+                verdict.mark(),
+                ANSI_ITALIC,
+                stats.to_human(),
+                ANSI_RESET
+            ),
+            _ => format!("{}\nnot solved.{}", header, verdict.mark()),
+        }
+    }
+}
+
+/// Runs `func` repeatedly: first a warm-up for a tenth of the budget, then timed samples until the budget is spent.
+/// At least one sample is always taken.
+pub fn measure<T>(mut func: impl FnMut() -> T, budget: Duration) -> BenchStats {
+    let warmup = Instant::now();
+    while warmup.elapsed() < budget / 10 {
+        black_box(func());
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+
+    while samples.is_empty() || (start.elapsed() < budget && samples.len() < MAX_SAMPLES) {
+        let timer = Instant::now();
+        black_box(func());
+        samples.push(timer.elapsed());
+    }
+
+    BenchStats::from_samples(samples)
+}
+
+/// Benchmarks a single solver call against `input`.
+/// Parts that are not solved, return an error or panic are not measured.
+pub fn bench_part<I: ?Sized, R: IntoOutcome>(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&I) -> R,
+    input: &I,
+    budget: Duration,
+) -> BenchResult {
+    let (answer, error) = match panics::catch(|| solver(input)).and_then(IntoOutcome::into_outcome)
+    {
+        Ok(answer) => (answer, None),
+        Err(e) => (None, Some(e)),
+    };
+    let stats = answer
+        .as_ref()
+        .map(|_| measure(|| solver(black_box(input)), budget));
+
+    BenchResult {
+        day,
+        part,
+        answer,
+        error,
+        stats,
+        parse: None,
+    }
+}
+
+/// Benchmarks parsing the input and both parts of a solution, spending up to `budget` on each.
+/// The first part carries the parse timings. If parsing the input panics, both parts are reported as failed.
+pub fn bench<S: Solution>(input: &str, budget: Duration) -> Vec<BenchResult> {
+    match panics::catch(|| S::parse(input)) {
+        Ok(parsed) => vec![
+            BenchResult {
+                parse: Some(measure(|| S::parse(black_box(input)), budget)),
+                ..bench_part(S::DAY, 1, S::part_one, &parsed, budget)
+            },
+            bench_part(S::DAY, 2, S::part_two, &parsed, budget),
+        ],
+        Err(e) => (1..=2)
+            .map(|part| BenchResult {
+                day: S::DAY,
+                part,
+                answer: None,
+                error: Some(format!("parse {}", e)),
+                stats: None,
+                parse: None,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [4, 1, 3, 2]
+            .iter()
+            .map(|n| Duration::from_nanos(*n))
+            .collect();
+        let stats = BenchStats::from_samples(samples);
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(2));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.stddev, Duration::from_nanos(1));
+    }
+
+    #[test]
+    fn test_measure_takes_at_least_one_sample() {
+        let stats = measure(|| 1 + 1, Duration::ZERO);
+        assert_eq!(stats.samples, 1);
+    }
+
+    #[test]
+    fn test_bench_part_skips_unsolved() {
+        let result = bench_part(1, 2, |_: &str| None::<u32>, "", Duration::from_millis(1));
+        assert!(result.stats.is_none());
+    }
+}
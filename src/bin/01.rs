@@ -1,13 +1,12 @@
+use advent_of_code::Solution;
+
 pub fn part_one(input: &str) -> Option<u32> {
     input
         .split("\n\n")
         .map(|group| {
             group
                 .split("\n")
-                .map(|val| match val.parse::<u32>() {
-                    Ok(v) => v,
-                    Err(_) => 0,
-                })
+                .map(|val| val.parse::<u32>().unwrap_or_default())
                 .sum()
         })
         .max()
@@ -19,10 +18,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         .map(|group| {
             group
                 .split("\n")
-                .map(|val| match val.parse::<u32>() {
-                    Ok(v) => v,
-                    Err(_) => 0,
-                })
+                .map(|val| val.parse::<u32>().unwrap_or_default())
                 .sum()
         })
         .collect::<Vec<u32>>();
@@ -33,25 +29,33 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(totals[..3].iter().sum())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day01);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_one(&input), Some(24000));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_two(&input), Some(45000));
-    }
+    advent_of_code::example_tests!(Day01);
 }
@@ -1,3 +1,5 @@
+use advent_of_code::Solution;
+
 enum MoveType {
     Rock,
     Paper,
@@ -128,25 +130,33 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(round_scores.sum())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day02);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
-    }
+    advent_of_code::example_tests!(Day02);
 }
@@ -1,3 +1,4 @@
+use advent_of_code::Solution;
 use std::collections::HashSet;
 
 const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
@@ -11,11 +12,11 @@ impl Backpack {
     fn from_string(input: &str) -> Backpack {
         let (first, second) = input.split_at(input.len() / 2);
 
-        let first_compartment: HashSet<char> = first.chars().into_iter().collect();
-        let second_compartment: HashSet<char> = second.chars().into_iter().collect();
+        let first_compartment: HashSet<char> = first.chars().collect();
+        let second_compartment: HashSet<char> = second.chars().collect();
 
         Backpack {
-            items: input.chars().into_iter().collect::<HashSet<char>>(),
+            items: input.chars().collect::<HashSet<char>>(),
             compartments: vec![first_compartment, second_compartment],
         }
     }
@@ -25,7 +26,7 @@ impl Backpack {
         Some(
             dupes
                 .iter()
-                .map(|char| score_item(&char).unwrap() as u32)
+                .map(|char| score_item(char).unwrap() as u32)
                 .sum(),
         )
     }
@@ -33,10 +34,7 @@ impl Backpack {
     fn find_duplicates(&self) -> Vec<char> {
         let first = &self.compartments[0];
         let second = &self.compartments[1];
-        first
-            .intersection(second)
-            .map(|i| *i)
-            .collect::<Vec<char>>()
+        first.intersection(second).copied().collect::<Vec<char>>()
     }
 }
 
@@ -54,10 +52,10 @@ fn find_duplicates(backpacks: Vec<Backpack>) -> Vec<char> {
 }
 
 fn score_item(item: &char) -> Option<u8> {
-    match ALPHABET.chars().position(|c| &c == item) {
-        Some(i) => Some((i + 1) as u8),
-        None => None,
-    }
+    ALPHABET
+        .chars()
+        .position(|c| &c == item)
+        .map(|i| (i + 1) as u8)
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -80,7 +78,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 
             find_duplicates(backpacks)
                 .iter()
-                .map(|char| score_item(&char).unwrap() as u32)
+                .map(|char| score_item(char).unwrap() as u32)
                 .sum::<u32>()
         })
         .sum::<u32>();
@@ -88,25 +86,33 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(score)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day03);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
-    }
+    advent_of_code::example_tests!(Day03);
 }
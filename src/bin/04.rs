@@ -1,3 +1,5 @@
+use advent_of_code::Solution;
+
 fn contains_range(range_a: Vec<u32>, range_b: Vec<u32>) -> bool {
     range_a[0] >= range_b[0] && range_a[1] <= range_b[1]
 }
@@ -44,25 +46,33 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(count as u32)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day04);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_one(&input), Some(2));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_two(&input), Some(4));
-    }
+    advent_of_code::example_tests!(Day04);
 }
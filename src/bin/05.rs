@@ -1,3 +1,5 @@
+use advent_of_code::Solution;
+
 struct State {
     stacks: Vec<Vec<char>>,
 }
@@ -9,13 +11,13 @@ impl State {
         let mut stacks = State::initialize_stacks(input.clone());
 
         input.iter().skip(1).for_each(|&line| {
-            for i in 0..stacks.len() {
+            for (i, stack) in stacks.iter_mut().enumerate() {
                 let index = 1 + (i * 4);
                 let item = line.chars().nth(index);
 
                 if let Some(val) = item {
                     if val.to_string() != " " {
-                        stacks[i].push(val);
+                        stack.push(val);
                     }
                 }
             }
@@ -79,7 +81,7 @@ impl State {
         let mut signature: Vec<char> = vec![];
         self.stacks.clone().into_iter().for_each(|stack| {
             if let Some(val) = stack.last() {
-                signature.push(val.clone());
+                signature.push(*val);
             }
         });
 
@@ -94,7 +96,7 @@ struct Instruction {
 }
 
 impl Instruction {
-    fn from_block(input: &str) -> Vec<Instruction> {
+    fn from_block(input: &str) -> Result<Vec<Instruction>, String> {
         let lines = input.lines().collect::<Vec<&str>>();
 
         lines
@@ -102,78 +104,92 @@ impl Instruction {
             .cloned()
             .rev()
             .take_while(|line| !line.is_empty())
-            .map(|line| Instruction::from_string(line))
-            .collect::<Vec<Instruction>>()
-            .into_iter()
-            .rev()
-            .collect()
+            .map(Instruction::from_string)
+            .collect::<Result<Vec<Instruction>, String>>()
+            .map(|instructions| instructions.into_iter().rev().collect())
     }
 
-    fn from_string(input: &str) -> Instruction {
+    fn from_string(input: &str) -> Result<Instruction, String> {
         let parts = input.split(" ").collect::<Vec<&str>>();
-
-        let quantity: u8 = parts[1].parse::<u8>().unwrap();
-        let from: u8 = parts[3].parse::<u8>().unwrap();
-        let to: u8 = parts[5].parse::<u8>().unwrap();
-
-        Instruction { quantity, from, to }
+        let number = |i: usize| -> Result<u8, String> {
+            parts
+                .get(i)
+                .and_then(|part| part.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid instruction \"{}\"", input))
+        };
+
+        Ok(Instruction {
+            quantity: number(1)?,
+            from: number(3)?,
+            to: number(5)?,
+        })
     }
 }
 
-pub fn part_one(input: &str) -> Option<String> {
+pub fn part_one(input: &str) -> Result<String, String> {
     let stack_state: Vec<&str> = input.lines().take_while(|line| !line.is_empty()).collect();
 
     let mut state = State::from_input(stack_state);
-    let instructions = Instruction::from_block(input.clone());
+    let instructions = Instruction::from_block(input)?;
 
     instructions
         .iter()
         .for_each(|instruction| state.apply_move_singular(instruction));
 
-    Some(state.get_top_stacks())
+    Ok(state.get_top_stacks())
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &str) -> Result<String, String> {
     let stack_state: Vec<&str> = input.lines().take_while(|line| !line.is_empty()).collect();
 
     let mut state = State::from_input(stack_state);
-    let instructions = Instruction::from_block(input.clone());
+    let instructions = Instruction::from_block(input)?;
 
     instructions
         .iter()
         .for_each(|instruction| state.apply_move_grouped(instruction));
 
-    Some(state.get_top_stacks())
+    Ok(state.get_top_stacks())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = &'a str;
+    type PartOne = Result<String, String>;
+    type PartTwo = Result<String, String>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Result<String, String> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Result<String, String> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day05);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".to_string()));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".to_string()));
-    }
+    advent_of_code::example_tests!(Day05);
 
     #[test]
     fn test_instruction_from_string() {
         let input = "move 3 from 1 to 3";
-        let instruction = Instruction::from_string(input);
+        let instruction = Instruction::from_string(input).unwrap();
         assert_eq!(instruction.quantity, 3);
         assert_eq!(instruction.from, 1);
         assert_eq!(instruction.to, 3);
+
+        assert!(Instruction::from_string("move x from 1 to 3").is_err());
     }
 }
@@ -1,3 +1,5 @@
+use advent_of_code::Solution;
+
 struct FrequencyTable {
     table: [u8; 26],
     duplicate_count: u8,
@@ -12,18 +14,18 @@ impl FrequencyTable {
     }
 
     fn increment(&mut self, index: usize) {
-        self.table[index] = self.table[index] + 1;
+        self.table[index] += 1;
 
         if self.table[index] == 2 {
-            self.duplicate_count = self.duplicate_count + 1;
+            self.duplicate_count += 1;
         }
     }
 
     fn decrement(&mut self, index: usize) {
-        self.table[index] = self.table[index] - 1;
+        self.table[index] -= 1;
 
         if self.table[index] == 1 {
-            self.duplicate_count = self.duplicate_count - 1;
+            self.duplicate_count -= 1;
         }
     }
 }
@@ -53,39 +55,47 @@ fn find_marker_improved(input: &str, sequence_size: u32) -> Option<u32> {
             }
         }
 
-        index = index + 1;
+        index += 1;
     }
 
     Some((index as u32) + 1)
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    find_marker_improved(&input, 4)
+    find_marker_improved(input, 4)
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    find_marker_improved(&input, 14)
+    find_marker_improved(input, 14)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day06);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_one(&input), Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_two(&input), Some(19));
-    }
+    advent_of_code::example_tests!(Day06);
 }
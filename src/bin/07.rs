@@ -1,3 +1,5 @@
+use advent_of_code::Solution;
+
 #[derive(Clone)]
 enum FileType {
     File,
@@ -80,7 +82,7 @@ fn calculate_directory_size(directory: File, files: Vec<File>) -> u32 {
         .clone()
         .into_iter()
         .filter(|f| f.path.starts_with(&directory.path[..]))
-        .map(|f| f.size.unwrap_or_else(|| 0))
+        .map(|f| f.size.unwrap_or(0))
         .sum::<u32>()
 }
 
@@ -92,7 +94,7 @@ fn change_directory(current_directory: &str, command: Command) -> String {
                 let new_path = parts[..(parts.len() - 2)].join("/");
                 format!("{}/", new_path)
             } else {
-                if current_directory != "" {
+                if !current_directory.is_empty() {
                     format!("{}{}/", current_directory, target)
                 } else {
                     format!("{}/", current_directory)
@@ -133,10 +135,7 @@ fn cumulative_directory_size_by_max_size(files: Vec<File>, max_size: u32) -> u32
     files
         .into_iter()
         .filter_map(|f| {
-            let is_directory = match f.file_type {
-                FileType::Directory => true,
-                _ => false,
-            };
+            let is_directory = matches!(f.file_type, FileType::Directory);
 
             match is_directory {
                 false => None,
@@ -190,27 +189,35 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(directory)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day07);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
-    }
+    advent_of_code::example_tests!(Day07);
 
     // #[test]
     // fn test_file_from_string_dir() {
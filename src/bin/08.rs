@@ -1,3 +1,4 @@
+use advent_of_code::Solution;
 use itertools::Itertools;
 
 enum SliceType {
@@ -15,7 +16,7 @@ impl Matrix {
     fn from_input(input: &str) -> Matrix {
         let lines = input.lines();
         let num_rows: u32 = lines.clone().count() as u32;
-        let num_columns: u32 = lines.collect::<Vec<&str>>().get(0).unwrap().len() as u32;
+        let num_columns: u32 = lines.collect::<Vec<&str>>().first().unwrap().len() as u32;
 
         let elems = Matrix::concat_input_lines(input);
 
@@ -29,15 +30,11 @@ impl Matrix {
     fn concat_input_lines(input: &str) -> Vec<u32> {
         input
             .lines()
-            .map(|line| {
+            .flat_map(|line| {
                 line.split("")
-                    .filter_map(|val| match val.parse::<u32>() {
-                        Ok(val) => Some(val),
-                        _ => None,
-                    })
+                    .filter_map(|val| val.parse::<u32>().ok())
                     .collect::<Vec<u32>>()
             })
-            .flatten()
             .collect::<Vec<u32>>()
     }
 
@@ -56,7 +53,7 @@ impl Matrix {
             .clone()
             .into_iter()
             .enumerate()
-            .filter(|(i, _elem)| self.is_elem_visible(&i))
+            .filter(|(i, _elem)| self.is_elem_visible(i))
             .count() as u32
     }
 
@@ -67,13 +64,14 @@ impl Matrix {
         let column = self.slice(SliceType::Column, col_index);
         let row = self.slice(SliceType::Row, row_index);
 
-        let mut visible = vec![];
-        visible.push(self.visible_by_slice(&column, SliceType::Column, col_index));
-        visible.push(self.visible_by_slice(&row, SliceType::Row, row_index));
+        let visible = vec![
+            self.visible_by_slice(&column, SliceType::Column, col_index),
+            self.visible_by_slice(&row, SliceType::Row, row_index),
+        ];
 
         let flattened = visible.into_iter().flatten().collect::<Vec<u32>>();
 
-        flattened.iter().any(|n| *n == *index as u32)
+        flattened.contains(&(*index as u32))
     }
 
     fn distance_by_elem(&self, elem: u32, index: &usize) -> u32 {
@@ -89,21 +87,21 @@ impl Matrix {
         column_distance * row_distance
     }
 
-    fn distance_by_slice(slice: &Vec<u32>, index: usize, elem: u32) -> u32 {
+    fn distance_by_slice(slice: &[u32], index: usize, elem: u32) -> u32 {
         let (left, right) = slice.split_at(index);
 
         let mut left_distance: u32 = 0;
         let mut right_distance: u32 = 0;
 
-        for n in left.into_iter().rev() {
-            left_distance = left_distance + 1;
+        for n in left.iter().rev() {
+            left_distance += 1;
             if n >= &elem {
                 break;
             }
         }
 
-        for n in right[1..].into_iter() {
-            right_distance = right_distance + 1;
+        for n in right[1..].iter() {
+            right_distance += 1;
             if n >= &elem {
                 break;
             }
@@ -114,7 +112,7 @@ impl Matrix {
 
     fn visible_by_slice(
         &self,
-        slice: &Vec<u32>,
+        slice: &[u32],
         slice_type: SliceType,
         slice_index: usize,
     ) -> Vec<u32> {
@@ -128,7 +126,7 @@ impl Matrix {
                     SliceType::Column => (i as u32 * self.num_rows) + slice_index as u32,
                 };
 
-                visible_elems.push(elem_index as u32);
+                visible_elems.push(elem_index);
                 max = *elem as i32;
             }
         }
@@ -138,10 +136,10 @@ impl Matrix {
             if *elem as i32 > max {
                 let rev_index = ((slice.len() - 1) - i) as u32;
                 let elem_index = match slice_type {
-                    SliceType::Row => (slice_index as u32 * self.num_columns) + rev_index as u32,
-                    SliceType::Column => (rev_index as u32 * self.num_rows) + slice_index as u32,
+                    SliceType::Row => (slice_index as u32 * self.num_columns) + rev_index,
+                    SliceType::Column => (rev_index * self.num_rows) + slice_index as u32,
                 };
-                visible_elems.push(elem_index as u32);
+                visible_elems.push(elem_index);
                 max = *elem as i32;
             }
         }
@@ -168,37 +166,43 @@ impl Matrix {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let matrix = Matrix::from_input(input);
+pub fn part_one(matrix: &Matrix) -> Option<u32> {
     Some(matrix.visible_elems())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let matrix = Matrix::from_input(input);
+pub fn part_two(matrix: &Matrix) -> Option<u32> {
     Some(matrix.max_distance())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Matrix;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Matrix {
+        Matrix::from_input(input)
+    }
+
+    fn part_one(matrix: &Matrix) -> Option<u32> {
+        part_one(matrix)
+    }
+
+    fn part_two(matrix: &Matrix) -> Option<u32> {
+        part_two(matrix)
+    }
 }
 
+advent_of_code::main!(Day08);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_one(&input), Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_two(&input), Some(8));
-    }
+    advent_of_code::example_tests!(Day08);
 
     #[test]
     fn test_matrix_col() {
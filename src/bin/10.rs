@@ -1,4 +1,4 @@
-use std::ops::Range;
+use advent_of_code::Solution;
 
 #[derive(Debug)]
 enum Instruction {
@@ -14,7 +14,7 @@ fn parse_instruction(input: &str) -> Instruction {
     }
 }
 
-struct CPU {
+struct Cpu {
     counter: u32,
     register_x: i32,
     delay: u32,
@@ -22,15 +22,15 @@ struct CPU {
     next_instruction: Option<Instruction>,
 }
 
-impl CPU {
-    fn from_input(input: &str) -> CPU {
+impl Cpu {
+    fn from_input(input: &str) -> Cpu {
         let instructions = input
             .lines()
-            .map(|line| parse_instruction(line))
+            .map(parse_instruction)
             .rev()
             .collect::<Vec<Instruction>>();
 
-        CPU {
+        Cpu {
             instructions,
             next_instruction: None,
             counter: 0,
@@ -60,14 +60,14 @@ impl CPU {
     }
 
     fn draw_pixel(&self) -> bool {
-        let range = ((self.register_x - 1)..=(self.register_x + 1));
+        let range = (self.register_x - 1)..=(self.register_x + 1);
 
         range.contains(&((self.counter - 1) as i32 % 40))
     }
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let mut cpu = CPU::from_input(input);
+    let mut cpu = Cpu::from_input(input);
     let mut signal_strength = 0;
 
     loop {
@@ -89,7 +89,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<String> {
-    let mut cpu = CPU::from_input(input);
+    let mut cpu = Cpu::from_input(input);
     let mut output: Vec<&str> = vec![];
 
     loop {
@@ -115,26 +115,33 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(printed)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<String>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<String> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day10);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        let expected_output = "##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....\n.".to_string();
-        assert_eq!(part_two(&input), Some(expected_output));
-    }
+    advent_of_code::example_tests!(Day10);
 }
@@ -1,3 +1,4 @@
+use advent_of_code::Solution;
 use num::Integer;
 use regex::Regex;
 
@@ -29,10 +30,7 @@ impl Monkey {
         let parts: Vec<&str> = line.split("Starting items: ").collect();
         parts[1]
             .split(", ")
-            .filter_map(|elem| match elem.parse::<u128>() {
-                Ok(val) => Some(val),
-                _ => None,
-            })
+            .filter_map(|elem| elem.parse::<u128>().ok())
             .collect::<Vec<u128>>()
     }
 
@@ -86,17 +84,17 @@ impl Monkey {
         match self.operation {
             Operation::AddTo(val) => worry_level += val as u128,
             Operation::Double => worry_level = worry_level * worry_level,
-            Operation::MultiplyBy(val) => worry_level = worry_level * (val as u128),
+            Operation::MultiplyBy(val) => worry_level *= val as u128,
         }
 
         if divide_worry {
-            worry_level = worry_level / 3;
+            worry_level /= 3;
         } else {
             worry_level %= lcm as u128
         }
 
         let test_result = match self.test {
-            Test::DivisibleBy(val) => worry_level % (val as u128) == 0,
+            Test::DivisibleBy(val) => worry_level.is_multiple_of(val as u128),
         };
 
         let action_type = match test_result {
@@ -141,13 +139,12 @@ struct Action {
 fn parse_monkeys(input: &str) -> Vec<Monkey> {
     input
         .split("\n\n")
-        .into_iter()
-        .map(|chunk| Monkey::from_input(chunk))
+        .map(Monkey::from_input)
         .collect::<Vec<Monkey>>()
 }
 
 fn simulate_monkeys(
-    monkeys: &mut Vec<Monkey>,
+    monkeys: &mut [Monkey],
     num_rounds: usize,
     divide_worry: bool,
     lcm: u32,
@@ -159,7 +156,7 @@ fn simulate_monkeys(
 
             for action in actions {
                 let ActionType::ThrowTo(id) = action.action_type;
-                let item = action.val.clone();
+                let item = action.val;
                 let monkey = &mut monkeys[id as usize];
 
                 monkey.add_item(item);
@@ -167,16 +164,16 @@ fn simulate_monkeys(
         }
     }
 
-    monkeys.sort_by(|a, b| b.counter.cmp(&a.counter));
+    monkeys.sort_by_key(|monkey| std::cmp::Reverse(monkey.counter));
 
     monkeys
-        .into_iter()
+        .iter_mut()
         .take(2)
         .map(|monkey| monkey.counter)
         .reduce(|accum, item| accum * item)
 }
 
-fn get_monkey_lcm(monkeys: &Vec<Monkey>) -> u32 {
+fn get_monkey_lcm(monkeys: &[Monkey]) -> u32 {
     monkeys
         .iter()
         .map(|monkey| match monkey.test {
@@ -200,32 +197,39 @@ pub fn part_two(input: &str) -> Option<u128> {
     simulate_monkeys(&mut monkeys, 10000, false, lcm)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u128>;
+    type PartTwo = Option<u128>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u128> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u128> {
+        part_two(input)
+    }
 }
 
+advent_of_code::main!(Day11);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_one(&input), Some(10605));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_two(&input), Some(2713310158));
-    }
+    advent_of_code::example_tests!(Day11);
 
     #[test]
     fn test_monkey_inspect_case_1() {
         let monkey = Monkey {
-            id: 0,
             counter: 0,
             items: vec![79, 78],
             operation: Operation::MultiplyBy(19),
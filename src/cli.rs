@@ -0,0 +1,201 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! The `aoc` command line tool. Each subcommand lives in a module of its own.
+//! The cargo aliases in `.cargo/config`, `src/main.rs` and the day binaries are thin wrappers around it.
+use std::process;
+
+use pico_args::Arguments;
+
+pub mod all;
+pub mod download;
+pub mod scaffold;
+pub mod solve;
+pub mod status;
+pub mod submit;
+pub mod test;
+
+pub const USAGE: &str = "\
+Usage: cargo aoc <command> [options]
+
+Commands:
+  scaffold <day>       Create the solution, input and example files of a day
+  download <day>       Download the input, puzzle description and example of a day
+  solve <day>          Run a day's solution
+  submit <day> <part>  Submit an answer
+  all [days]           Run the solutions of all or some days
+  bench [days]         Benchmark the solutions of all or some days
+  test [days]          Check solutions against the expected answers of their examples
+  status               Show the progress of all days
+
+The aliases `cargo scaffold`, `cargo download`, `cargo submit`, `cargo solve` and `cargo all` still work.";
+
+/// Error of a subcommand.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// Arguments are missing or invalid.
+    Args(String),
+    /// The command failed. The message is printed before exiting.
+    Message(String),
+    /// The command failed and already reported why, e.g. an answer that differs from the accepted one.
+    Failed,
+}
+
+impl From<pico_args::Error> for Error {
+    fn from(e: pico_args::Error) -> Error {
+        Error::Args(e.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(e: String) -> Error {
+        Error::Message(e)
+    }
+}
+
+pub type Result = std::result::Result<(), Error>;
+
+/// Runs a subcommand with the arguments that follow it.
+pub fn run(command: &str, args: Arguments) -> Result {
+    match command {
+        "scaffold" => scaffold::run(args),
+        "download" => download::run(args),
+        "solve" => solve::run(args),
+        "submit" => submit::run(args),
+        "all" => all::run(args, false),
+        "bench" => all::run(args, true),
+        "test" => test::run(args),
+        "status" => status::run(args),
+        "help" => {
+            finish(args)?;
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(Error::Args(format!("unknown command \"{}\"", command))),
+    }
+}
+
+/// Fails on arguments that no flag of the command consumed, most likely misspelled flags.
+/// Call it after parsing all arguments of a command.
+pub fn finish(args: Arguments) -> Result {
+    let unused: Vec<String> = args
+        .finish()
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+
+    if unused.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Args(format!(
+            "unexpected argument(s): {}",
+            unused.join(" ")
+        )))
+    }
+}
+
+/// Entry point of the `aoc` binary: runs the subcommand named by the first argument.
+pub fn main() -> ! {
+    load_config();
+    all::run_worker_from_env();
+
+    let mut args = Arguments::from_env();
+
+    if args.contains(["-h", "--help"]) {
+        println!("{}", USAGE);
+        process::exit(0);
+    }
+
+    match args.subcommand() {
+        Ok(Some(command)) => exit(run(&command, args)),
+        Ok(None) => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+        Err(e) => exit(Err(e.into())),
+    }
+}
+
+/// Entry point of a wrapper binary: runs `command` with the arguments of the process.
+pub fn exec(command: &str) -> ! {
+    load_config();
+    all::run_worker_from_env();
+    exit(run(command, Arguments::from_env()))
+}
+
+/// Exits if `aoc.toml` is invalid, before any command relies on it.
+fn load_config() {
+    if let Err(e) = crate::config::load() {
+        exit(Err(e.into()));
+    }
+}
+
+/// Reports the result of a command and exits with its status.
+pub fn exit(result: Result) -> ! {
+    match result {
+        Ok(()) => process::exit(0),
+        Err(Error::Args(e)) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("Run \"cargo aoc help\" to list the available commands.");
+            process::exit(1);
+        }
+        Err(Error::Message(e)) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+        Err(Error::Failed) => process::exit(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Arguments {
+        Arguments::from_vec(args.iter().map(|arg| arg.into()).collect())
+    }
+
+    #[test]
+    fn test_run_unknown_command() {
+        assert_eq!(
+            run("sovle", args(&[])),
+            Err(Error::Args("unknown command \"sovle\"".into()))
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_args() {
+        assert!(matches!(run("solve", args(&["26"])), Err(Error::Args(_))));
+        assert!(matches!(
+            run("submit", args(&["1", "3"])),
+            Err(Error::Args(_))
+        ));
+    }
+
+    #[test]
+    fn test_run_invalid_day() {
+        for command in ["download", "submit", "scaffold", "solve"] {
+            assert_eq!(
+                run(command, args(&["26", "1"])),
+                Err(Error::Args(
+                    "failed to parse '26': \"26\" is not a day between 1 and 25".into()
+                )),
+                "{}",
+                command
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_unused_args() {
+        assert_eq!(
+            run("scaffold", args(&["12", "--dry-rn"])),
+            Err(Error::Args("unexpected argument(s): --dry-rn".into()))
+        );
+        assert_eq!(
+            run("status", args(&["--verbose"])),
+            Err(Error::Args("unexpected argument(s): --verbose".into()))
+        );
+    }
+}
@@ -0,0 +1,513 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `cargo all [days]` and `cargo aoc bench [days]`: runs or benchmarks the solutions of several days.
+use std::collections::BTreeMap;
+use std::env;
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use pico_args::Arguments;
+
+use super::Error;
+use crate::answers::{self, Answers, Verdict};
+use crate::bench::{self, BenchResult};
+use crate::history::{self, History, Run};
+use crate::readme;
+use crate::watchdog::{self, Limits};
+use crate::{registry, report, PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+
+/// Environment variable that makes a child started by `run_with_limits` run a single day.
+const WORKER_ENV_VAR: &str = "AOC_WORKER";
+
+struct Args {
+    days: Option<Vec<u8>>,
+    spawn: bool,
+    bench: bool,
+    bench_time: Duration,
+    save: bool,
+    compare: bool,
+    threshold: f64,
+    readme: bool,
+    lock: bool,
+    jobs: Option<usize>,
+    limits: Limits,
+}
+
+/// Parses the flags of `cargo all`. Flags that are not passed fall back to the `[runner]` section of `aoc.toml`.
+/// Benchmarks measure one day at a time in this process, so they ignore the configured `jobs` and time limits.
+/// `--spawn` ignores the configured time limits. Only flags that are passed conflict with these modes.
+fn parse_args(mut args: Arguments, bench: bool) -> Result<Args, Error> {
+    let defaults = &crate::config::get().runner;
+
+    let mut parsed = Args {
+        spawn: args.contains("--spawn"),
+        bench: args.contains("--bench") || bench,
+        save: args.contains("--save"),
+        compare: args.contains("--compare"),
+        readme: args.contains("--readme"),
+        lock: args.contains("--lock"),
+        limits: Limits {
+            day: args
+                .opt_value_from_str("--timeout")?
+                .map(Duration::from_millis),
+            part: args
+                .opt_value_from_str("--part-timeout")?
+                .map(Duration::from_millis),
+        },
+        jobs: match (
+            args.contains("--parallel"),
+            args.opt_value_from_str("--jobs")?,
+        ) {
+            (_, Some(jobs)) => Some(jobs),
+            (true, None) => Some(thread::available_parallelism().map_or(1, |n| n.get())),
+            (false, None) => None,
+        },
+        threshold: args
+            .opt_value_from_str("--threshold")?
+            .or(defaults.threshold)
+            .unwrap_or(history::DEFAULT_THRESHOLD),
+        bench_time: args
+            .opt_value_from_str("--bench-time")?
+            .or(defaults.bench_time)
+            .map_or(bench::DEFAULT_BUDGET, Duration::from_millis),
+        days: args.opt_free_from_fn(crate::parse_days)?,
+    };
+
+    super::finish(args)?;
+
+    if !(parsed.bench || parsed.save || parsed.compare) {
+        parsed.jobs = parsed.jobs.or(defaults.jobs);
+
+        if !parsed.spawn {
+            let limits = &mut parsed.limits;
+            limits.day = limits.day.or(defaults.timeout.map(Duration::from_millis));
+            limits.part = limits
+                .part
+                .or(defaults.part_timeout.map(Duration::from_millis));
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn read_input(day: u8) -> Option<String> {
+    crate::try_read_file("inputs", day).ok()
+}
+
+/// Runs a day inside this process using the solution registry.
+fn run_in_process(day: u8) -> Vec<PartResult> {
+    match (registry::get(day), read_input(day)) {
+        (Some(solution), Some(input)) => solution.run(&input),
+        _ => vec![],
+    }
+}
+
+/// Runs a day in a child process of this binary that is killed once it exceeds the time limits.
+fn run_with_limits(day: u8, limits: &Limits) -> Vec<PartResult> {
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("Failed to locate the current executable: {}", e);
+            return vec![];
+        }
+    };
+
+    let mut command = Command::new(exe);
+    command.env(WORKER_ENV_VAR, day.to_string());
+
+    watchdog::run(command, day, limits).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        vec![]
+    })
+}
+
+/// Entry point of a child started by `run_with_limits`: prints each part as a JSON line once it is done.
+/// Does nothing unless the process was started as such a child.
+pub fn run_worker_from_env() {
+    let day = match env::var(WORKER_ENV_VAR).map(|day| day.parse::<u8>()) {
+        Ok(Ok(day)) => day,
+        _ => return,
+    };
+
+    if let (Some(solution), Some(input)) = (registry::get(day), read_input(day)) {
+        solution.run_with(&input, &mut |result| println!("{}", result.to_json()));
+    }
+
+    process::exit(0);
+}
+
+/// Benchmarks a day inside this process using the solution registry.
+fn bench_in_process(day: u8, budget: Duration) -> Vec<BenchResult> {
+    match (registry::get(day), read_input(day)) {
+        (Some(solution), Some(input)) => solution.bench(&input, budget),
+        _ => vec![],
+    }
+}
+
+/// Formats the output of a day: a header followed by the rendered part results.
+/// Days without a solution module are reported as not scaffolded.
+fn format_day(day: u8, parts: &[String]) -> String {
+    let body = if !crate::is_scaffolded(day) {
+        "Not scaffolded.".to_string()
+    } else if parts.is_empty() {
+        "Not solved.".to_string()
+    } else {
+        parts.join("\n")
+    };
+
+    format!(
+        "----------\n{}| Day {:02} |{}\n----------\n{}",
+        ANSI_BOLD, day, ANSI_RESET, body
+    )
+}
+
+/// Runs a day once. Returns its formatted output along with the results.
+fn run_day(day: u8, spawn: bool, limits: &Limits, answers: &Answers) -> (String, Vec<PartResult>) {
+    let results = if !crate::is_scaffolded(day) {
+        vec![]
+    } else if spawn {
+        run_with_cargo(day)
+    } else if limits.is_set() {
+        run_with_limits(day, limits)
+    } else {
+        run_in_process(day)
+    };
+
+    let parts: Vec<String> = results
+        .iter()
+        .map(|result| result.to_human(&answers.check(result)))
+        .collect();
+    (format_day(day, &parts), results)
+}
+
+/// Runs days one after another, printing each day's output as soon as it is done.
+fn run_days_serial(
+    days: &[u8],
+    spawn: bool,
+    limits: &Limits,
+    answers: &Answers,
+) -> Vec<PartResult> {
+    days.iter()
+        .flat_map(|day| {
+            let (output, results) = run_day(*day, spawn, limits, answers);
+            println!("{}", output);
+            results
+        })
+        .collect()
+}
+
+/// Runs days on a pool of `jobs` worker threads.
+/// Each day's output is buffered and printed in day order once all previous days are done.
+fn run_days_parallel(
+    days: &[u8],
+    spawn: bool,
+    jobs: usize,
+    limits: &Limits,
+    answers: &Answers,
+) -> Vec<PartResult> {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let tx = tx.clone();
+            let next = &next;
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let day = match days.get(index) {
+                    Some(day) => *day,
+                    None => break,
+                };
+
+                if tx
+                    .send((index, run_day(day, spawn, limits, answers)))
+                    .is_err()
+                {
+                    break;
+                }
+            });
+        }
+
+        // workers hold the remaining senders, so the loop below ends once all of them are done.
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_print = 0;
+        let mut results = vec![];
+
+        for (index, day_output) in rx {
+            pending.insert(index, day_output);
+
+            while let Some((output, day_results)) = pending.remove(&next_to_print) {
+                println!("{}", output);
+                results.extend(day_results);
+                next_to_print += 1;
+            }
+        }
+
+        results
+    })
+}
+
+/// Benchmarks a day and prints its statistics.
+fn bench_day(day: u8, budget: Duration, answers: &Answers) -> Vec<BenchResult> {
+    let results = bench_in_process(day, budget);
+    let parts: Vec<String> = results
+        .iter()
+        .map(|result| {
+            let verdict = answers.check_answer(result.day, result.part, result.answer.as_deref());
+            result.to_human(&verdict)
+        })
+        .collect();
+
+    println!("{}", format_day(day, &parts));
+
+    results
+}
+
+/// Prints how the medians of `current` compare to `baseline` and returns whether any part regressed.
+fn print_comparison(baseline: &Run, current: &Run, threshold: f64) -> bool {
+    println!("----------");
+    println!(
+        "{}Compared to {} ({}):{}",
+        ANSI_BOLD, baseline.commit, baseline.date, ANSI_RESET
+    );
+
+    let comparisons = history::compare(baseline, current, threshold);
+
+    for c in &comparisons {
+        let line = format!(
+            "Day {:02} Part {}: {:.2?} -> {:.2?} ({:+.2}%)",
+            c.day, c.part, c.baseline, c.current, c.change
+        );
+
+        if c.regressed {
+            println!(
+                "{}{} slower than {}%{}",
+                ANSI_RED, line, threshold, ANSI_RESET
+            );
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    comparisons.iter().any(|c| c.regressed)
+}
+
+/// Prints the total time spent, broken down into parsing the inputs and solving each part.
+fn print_totals(results: &[PartResult]) {
+    let ms = |duration: Duration| duration.as_secs_f64() * 1000_f64;
+
+    let parse: Duration = results
+        .iter()
+        .filter_map(|result| result.parse_elapsed)
+        .sum();
+    let solve = |part: u8| -> Duration {
+        results
+            .iter()
+            .filter(|result| result.part == part && result.is_solved())
+            .map(|result| result.elapsed)
+            .sum()
+    };
+    let (part_one, part_two) = (solve(1), solve(2));
+
+    println!(
+        "{}Total:{} {}{:.2}ms (parse: {:.2}ms, part 1: {:.2}ms, part 2: {:.2}ms){}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        ms(parse + part_one + part_two),
+        ms(parse),
+        ms(part_one),
+        ms(part_two),
+        ANSI_RESET
+    );
+}
+
+/// Runs a day's binary through cargo and collects the results it reports.
+fn run_with_cargo(day: u8) -> Vec<PartResult> {
+    let cmd = Command::new("cargo")
+        .args(["run", "--release", "--bin", &format!("{:02}", day)])
+        .env(report::JSON_ENV_VAR, "1")
+        .output()
+        .unwrap();
+
+    let output = String::from_utf8(cmd.stdout).unwrap();
+    output.lines().filter_map(PartResult::from_json).collect()
+}
+
+/// Runs the selected days, or benchmarks them if `bench` is set.
+pub fn run(args: Arguments, bench: bool) -> super::Result {
+    let args = parse_args(args, bench)?;
+
+    let days = args.days.clone().unwrap_or_else(|| (1..=25).collect());
+
+    let bench = args.bench || args.save || args.compare;
+
+    if bench && args.spawn {
+        return Err(Error::Args(
+            "--bench runs solutions in-process and cannot be combined with --spawn.".into(),
+        ));
+    }
+
+    if bench && args.jobs.is_some() {
+        return Err(Error::Args(
+            "--bench measures one solution at a time and cannot be combined with --parallel."
+                .into(),
+        ));
+    }
+
+    // the table is rendered from the results of this run, so a partial run would drop the other days.
+    if args.readme && args.days.is_some() {
+        return Err(Error::Args(
+            "--readme rewrites the table for all days and cannot be combined with a selection of days."
+                .into(),
+        ));
+    }
+
+    if args.limits.is_set() && (bench || args.spawn) {
+        return Err(Error::Args(
+            "--timeout and --part-timeout run each day in a child process of this binary and cannot be combined with --bench or --spawn.".into(),
+        ));
+    }
+
+    let answers_path = answers::answers_path();
+    let mut answers =
+        Answers::load(&answers_path).map_err(|e| format!("Failed to load answers: {}", e))?;
+
+    let mut regressed = false;
+
+    let results: Vec<PartResult> = if bench {
+        let results: Vec<BenchResult> = days
+            .into_iter()
+            .flat_map(|day| bench_day(day, args.bench_time, &answers))
+            .collect();
+
+        let run = Run::from_results(&results);
+        regressed = bench_history(&run, &args)?;
+
+        // report the median as the timing of a benchmarked part and its parsing.
+        results
+            .into_iter()
+            .map(|result| PartResult {
+                error: result.error,
+                parse_elapsed: result.parse.map(|stats| stats.median),
+                ..PartResult::new(
+                    result.day,
+                    result.part,
+                    result.answer,
+                    result.stats.map_or(Duration::ZERO, |stats| stats.median),
+                )
+            })
+            .collect()
+    } else {
+        match args.jobs {
+            Some(jobs) => run_days_parallel(&days, args.spawn, jobs, &args.limits, &answers),
+            None => run_days_serial(&days, args.spawn, &args.limits, &answers),
+        }
+    };
+
+    print_totals(&results);
+
+    // parts that returned an error or panicked are failed, parts without an answer are merely not solved yet.
+    let solved = results.iter().filter(|result| result.is_solved()).count();
+    let failed = results.iter().filter(|result| result.is_failed()).count();
+    let unsolved = results.len() - solved - failed;
+
+    let failed_summary = format!("{} failed", failed);
+    println!(
+        "{}Parts:{} {} solved, {} not solved, {}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        solved,
+        unsolved,
+        if failed > 0 {
+            format!("{}{}{}", ANSI_RED, failed_summary, ANSI_RESET)
+        } else {
+            failed_summary
+        }
+    );
+
+    if args.readme {
+        let readme_path = readme::readme_path();
+        readme::update(&readme_path, &results, crate::config::get().year)
+            .map_err(|e| format!("Failed to update readme: {}", e))?;
+        println!("Updated timings in \"{}\".", readme_path.display());
+    }
+
+    let mismatches = results
+        .iter()
+        .filter(|result| matches!(answers.check(result), Verdict::Incorrect(_)))
+        .count();
+
+    if args.lock {
+        let locked = answers.lock(&results);
+        answers
+            .save(&answers_path)
+            .map_err(|e| format!("Failed to save answers: {}", e))?;
+        println!(
+            "Locked {} answer(s) in \"{}\".",
+            locked,
+            answers_path.display()
+        );
+    }
+
+    if mismatches > 0 {
+        println!(
+            "{}{} answer(s) differ from the accepted answers.{}",
+            ANSI_RED, mismatches, ANSI_RESET
+        );
+        if args.lock {
+            println!(
+                "Accepted answers are never replaced. Remove them from \"{}\" to accept the new answers.",
+                answers_path.display()
+            );
+        }
+    }
+
+    if regressed || failed > 0 || mismatches > 0 {
+        return Err(Error::Failed);
+    }
+
+    Ok(())
+}
+
+/// Compares a benchmark run against the last recorded one and / or appends it to the history file.
+/// Returns whether a regression was detected.
+fn bench_history(run: &Run, args: &Args) -> Result<bool, String> {
+    if !args.save && !args.compare {
+        return Ok(false);
+    }
+
+    let path = &history::history_path();
+    let mut history =
+        History::load(path).map_err(|e| format!("Failed to load benchmark history: {}", e))?;
+
+    let regressed = match (args.compare, history.last()) {
+        (true, Some(baseline)) => print_comparison(baseline, run, args.threshold),
+        (true, None) => {
+            println!(
+                "No recorded baseline in \"{}\" to compare to.",
+                path.display()
+            );
+            false
+        }
+        _ => false,
+    };
+
+    if args.save {
+        history.runs.push(run.clone());
+        history
+            .save(path)
+            .map_err(|e| format!("Failed to save benchmark history: {}", e))?;
+        println!("Saved benchmark results to \"{}\".", path.display());
+    }
+
+    Ok(regressed)
+}
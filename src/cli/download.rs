@@ -0,0 +1,158 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `cargo download <day>`: downloads the input and puzzle description of a day and extracts its example.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pico_args::Arguments;
+
+use crate::client::{self, Client};
+use crate::puzzle;
+
+struct Args {
+    day: u8,
+    year: Option<i16>,
+    force: bool,
+    example: Option<usize>,
+    session_file: Option<PathBuf>,
+    base_url: Option<String>,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, super::Error> {
+    let parsed = Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        force: args.contains(["-f", "--force"]),
+        example: args.opt_value_from_str(["-e", "--example"])?,
+        session_file: args.opt_value_from_str("--session-file")?,
+        base_url: args.opt_value_from_str("--base-url")?,
+        day: args.free_from_fn(crate::parse_day)?,
+    };
+
+    super::finish(args)?;
+    Ok(parsed)
+}
+
+/// Whether a file exists and has content. `scaffold` creates empty input and example files.
+fn has_content(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|meta| meta.len() > 0)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    match path.parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+    .and_then(|_| client::write_atomic(path, contents))
+    .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
+}
+
+fn download_input(client: &Client, year: i16, args: &Args) -> super::Result {
+    let input_path = crate::input_path("inputs", args.day);
+
+    // inputs never change, only refetch if asked to.
+    if has_content(&input_path) && !args.force {
+        println!(
+            "Input \"{}\" already exists, skipping download. Pass --force to download it again.",
+            input_path.display()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "Downloading input from {}...",
+        client.input_url(year, args.day)
+    );
+
+    let input = client
+        .input(year, args.day)
+        .map_err(|e| format!("Failed to download input: {}", e))?;
+
+    write_file(&input_path, &input)?;
+    println!("Wrote input to \"{}\".", input_path.display());
+
+    Ok(())
+}
+
+/// Downloads the puzzle description and extracts the example input from it.
+/// The description is always refreshed, as part two is only included once part one is solved.
+fn download_puzzle(client: &Client, year: i16, args: &Args) -> super::Result {
+    println!(
+        "Downloading puzzle from {}...",
+        client.puzzle_url(year, args.day)
+    );
+
+    let html = client
+        .puzzle(year, args.day)
+        .map_err(|e| format!("Failed to download puzzle: {}", e))?;
+
+    let puzzle_path = crate::folder_path("puzzles").join(format!("{:02}.md", args.day));
+
+    write_file(&puzzle_path, &puzzle::to_markdown(&html))?;
+    println!("Wrote puzzle description to \"{}\".", puzzle_path.display());
+
+    let examples = puzzle::examples(&html);
+    let example_path = crate::input_path("examples", args.day);
+
+    // keep hand-edited examples unless an example was picked explicitly.
+    if has_content(&example_path) && !args.force && args.example.is_none() {
+        return Ok(());
+    }
+
+    let selected = args.example.unwrap_or(1);
+
+    match examples.get(selected.wrapping_sub(1)) {
+        Some(example) => {
+            write_file(&example_path, example)?;
+            println!(
+                "Wrote example {} of {} to \"{}\".",
+                selected,
+                examples.len(),
+                example_path.display()
+            );
+        }
+        None if examples.is_empty() => println!("Puzzle does not contain an example."),
+        None => {
+            return Err(format!(
+                "Puzzle contains {} example(s), there is no example {}.",
+                examples.len(),
+                selected
+            )
+            .into())
+        }
+    }
+
+    if examples.len() > 1 {
+        println!("Pass --example <n> to pick a different one:");
+        for (i, example) in examples.iter().enumerate() {
+            println!(
+                "  {}: {} ({} lines)",
+                i + 1,
+                example.lines().next().unwrap_or(""),
+                example.lines().count()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub fn run(args: Arguments) -> super::Result {
+    let args = parse_args(args)?;
+
+    let session_path = args.session_file.clone().or_else(client::session_path);
+    let session = client::read_session(session_path.as_deref())?;
+
+    let year = args.year.unwrap_or_else(client::default_year);
+    let base_url = args.base_url.clone().unwrap_or_else(client::base_url);
+    let client = Client::new(&base_url, &session);
+
+    download_input(&client, year, &args)?;
+    download_puzzle(&client, year, &args)?;
+
+    println!("---");
+    println!("🎄 Successfully downloaded day {}.", args.day);
+
+    Ok(())
+}
@@ -0,0 +1,384 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `cargo scaffold <day>`: creates the solution module, input, example and expected answers files of a day.
+//!
+//! All files are planned before anything is written. If writing one of them fails, the files written
+//! so far are removed again, so a day is either scaffolded completely or not at all.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use pico_args::Arguments;
+
+use super::Error;
+use crate::template::{self, Placeholders};
+use crate::{client, puzzle};
+
+struct Args {
+    day: u8,
+    examples: Vec<String>,
+    template: Option<String>,
+    dry_run: bool,
+    force: bool,
+}
+
+const EXPECTED_TEMPLATE: &str = r###"# Expected answers for the examples, one per line:
+# 1 = <answer of part one for {{day_padded}}.txt>
+# 2 = <answer of part two for {{day_padded}}.txt, or {{day_padded}}-2.txt if it exists>
+# a:1 = <answer of part one for {{day_padded}}-a.txt>
+"###;
+
+fn parse_args(mut args: Arguments) -> Result<Args, Error> {
+    let parsed = Args {
+        examples: args.values_from_str(["-e", "--example"])?,
+        template: args.opt_value_from_str(["-t", "--template"])?,
+        dry_run: args.contains("--dry-run"),
+        force: args.contains(["-f", "--force"]),
+        day: args.free_from_fn(crate::parse_day).map_err(|e| match e {
+            pico_args::Error::MissingArgument => Error::Args(
+                "Need to specify a day (as integer). example: `cargo scaffold 7`".into(),
+            ),
+            e => e.into(),
+        })?,
+    };
+
+    super::finish(args)?;
+    Ok(parsed)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    Create,
+    /// Replaces a file with `--force`. Its contents are restored if a later step fails.
+    Overwrite,
+    /// Leaves an existing file untouched.
+    Keep,
+}
+
+/// A file that scaffolding a day creates.
+struct Step {
+    name: &'static str,
+    path: PathBuf,
+    contents: String,
+    action: Action,
+}
+
+impl Step {
+    /// Plans to create `path`. An existing file is only replaced if `overwrite` is set.
+    fn new(name: &'static str, path: PathBuf, contents: String, overwrite: bool) -> Step {
+        let action = match (path.exists(), overwrite) {
+            (false, _) => Action::Create,
+            (true, true) => Action::Overwrite,
+            (true, false) => Action::Keep,
+        };
+
+        Step {
+            name,
+            path,
+            contents,
+            action,
+        }
+    }
+
+    fn describe(&self, dry_run: bool) -> String {
+        let empty = if self.contents.is_empty() {
+            "empty "
+        } else {
+            ""
+        };
+        let path = display(&self.path);
+
+        match (self.action, dry_run) {
+            (Action::Create, false) => format!("Created {}{} \"{}\"", empty, self.name, path),
+            (Action::Create, true) => format!("Would create {}{} \"{}\"", empty, self.name, path),
+            (Action::Overwrite, false) => format!("Regenerated {} \"{}\"", self.name, path),
+            (Action::Overwrite, true) => format!("Would regenerate {} \"{}\"", self.name, path),
+            (Action::Keep, false) => format!("Kept existing {} \"{}\"", self.name, path),
+            (Action::Keep, true) => format!("Would keep existing {} \"{}\"", self.name, path),
+        }
+    }
+}
+
+/// What `apply` changed, so it can be undone.
+enum Change {
+    Created(PathBuf),
+    Overwritten(PathBuf, Vec<u8>),
+    CreatedDir(PathBuf),
+}
+
+/// Creates the missing directories of `path`, which may have been moved to a new location in `aoc.toml`.
+fn create_parents(path: &Path, changes: &mut Vec<Change>) -> io::Result<()> {
+    let mut missing: Vec<&Path> = path
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+        .collect();
+
+    while let Some(dir) = missing.pop() {
+        fs::create_dir(dir)?;
+        changes.push(Change::CreatedDir(dir.to_path_buf()));
+    }
+
+    Ok(())
+}
+
+fn write_step(step: &Step, changes: &mut Vec<Change>) -> io::Result<()> {
+    match step.action {
+        Action::Create => {
+            create_parents(&step.path, changes)?;
+            // `create_new` never clobbers a file that appeared after planning.
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&step.path)?;
+            changes.push(Change::Created(step.path.clone()));
+            file.write_all(step.contents.as_bytes())
+        }
+        Action::Overwrite => {
+            let previous = fs::read(&step.path)?;
+            changes.push(Change::Overwritten(step.path.clone(), previous));
+            fs::write(&step.path, &step.contents)
+        }
+        Action::Keep => Ok(()),
+    }
+}
+
+/// Undoes `changes` in reverse order. Failures are reported, but do not stop the rollback.
+fn rollback(changes: Vec<Change>) {
+    for change in changes.into_iter().rev() {
+        let (path, result) = match change {
+            Change::Created(path) => {
+                let result = fs::remove_file(&path);
+                (path, result)
+            }
+            Change::Overwritten(path, previous) => {
+                let result = fs::write(&path, previous);
+                (path, result)
+            }
+            Change::CreatedDir(path) => {
+                let result = fs::remove_dir(&path);
+                (path, result)
+            }
+        };
+
+        if let Err(e) = result {
+            eprintln!("Failed to roll back \"{}\": {}", display(&path), e);
+        }
+    }
+}
+
+/// Writes all steps, or none of them: if a step fails, the changes of the previous steps are undone.
+fn apply(steps: &[Step]) -> Result<(), String> {
+    let mut changes = vec![];
+
+    for step in steps {
+        if let Err(e) = write_step(step, &mut changes) {
+            rollback(changes);
+            return Err(format!(
+                "Failed to write {} \"{}\": {}. No files were changed.",
+                step.name,
+                display(&step.path),
+                e
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders a path relative to the project directory, e.g. `src/inputs/05.txt`.
+fn display(path: &Path) -> String {
+    path.strip_prefix(crate::project_dir())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Renders the solution module of a day from its template.
+fn render_module(day: u8, template: Option<&str>) -> Result<String, String> {
+    let module = template::load(&crate::config::get().templates, template)
+        .map_err(|e| format!("Failed to load template: {}", e))?;
+
+    // the title is only known if the puzzle was downloaded before scaffolding.
+    let title = fs::read_to_string(crate::folder_path("puzzles").join(format!("{:02}.md", day)))
+        .ok()
+        .and_then(|markdown| puzzle::title(&markdown))
+        .unwrap_or_else(|| format!("Day {}", day));
+
+    let placeholders = Placeholders {
+        day,
+        year: client::default_year(),
+        title,
+    };
+
+    Ok(template::render(&module, &placeholders))
+}
+
+/// Plans the files of a day. Input and example files are never replaced, as they may hold downloaded data;
+/// `--force` only regenerates the solution module.
+fn plan(args: &Args) -> Result<Vec<Step>, Error> {
+    let day = args.day;
+    let mut steps = vec![];
+
+    // additional examples can be added to a day that is already scaffolded.
+    let add_examples = !args.examples.is_empty() && crate::is_scaffolded(day) && !args.force;
+
+    if !add_examples {
+        if crate::is_scaffolded(day) && !args.force {
+            return Err(format!(
+                "Day {:02} is already scaffolded. Pass --force to regenerate \"{}\" from its template.",
+                day,
+                display(&crate::module_path(day))
+            )
+            .into());
+        }
+
+        steps.push(Step::new(
+            "module file",
+            crate::module_path(day),
+            render_module(day, args.template.as_deref())?,
+            true,
+        ));
+        steps.push(Step::new(
+            "input file",
+            crate::input_path("inputs", day),
+            String::new(),
+            false,
+        ));
+        steps.push(Step::new(
+            "example file",
+            crate::input_path("examples", day),
+            String::new(),
+            false,
+        ));
+        steps.push(Step::new(
+            "expected answers file",
+            crate::examples::expected_path(day),
+            EXPECTED_TEMPLATE.replace("{{day_padded}}", &format!("{:02}", day)),
+            false,
+        ));
+    }
+
+    for name in &args.examples {
+        steps.push(Step::new(
+            "example file",
+            crate::named_input_path("examples", day, name),
+            String::new(),
+            false,
+        ));
+    }
+
+    Ok(steps)
+}
+
+pub fn run(args: Arguments) -> super::Result {
+    let args = parse_args(args)?;
+
+    let steps = plan(&args)?;
+
+    if !args.dry_run {
+        apply(&steps)?;
+    }
+
+    for step in &steps {
+        println!("{}", step.describe(args.dry_run));
+    }
+
+    println!("---");
+
+    if args.dry_run {
+        println!(
+            "🎄 Dry run, no files were changed. Run without --dry-run to scaffold day {:02}.",
+            args.day
+        );
+    } else {
+        println!(
+            "🎄 Type `cargo solve {:02}` to run your solution.",
+            args.day
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_step_actions() {
+        let dir = temp_dir("actions");
+        let existing = dir.join("01.txt");
+        fs::write(&existing, "input").unwrap();
+
+        let missing = dir.join("02.txt");
+
+        assert_eq!(
+            Step::new("input file", existing.clone(), String::new(), false).action,
+            Action::Keep
+        );
+        assert_eq!(
+            Step::new("module file", existing, String::new(), true).action,
+            Action::Overwrite
+        );
+        assert_eq!(
+            Step::new("input file", missing, String::new(), false).action,
+            Action::Create
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply() {
+        let dir = temp_dir("apply");
+        let module = dir.join("01.rs");
+        fs::write(&module, "old").unwrap();
+        let input = dir.join("inputs").join("01.txt");
+
+        let steps = vec![
+            Step::new("module file", module.clone(), "new".into(), true),
+            Step::new("input file", input.clone(), String::new(), false),
+        ];
+        assert_eq!(apply(&steps), Ok(()));
+        assert_eq!(fs::read_to_string(&module).unwrap(), "new");
+        assert_eq!(fs::read_to_string(&input).unwrap(), "");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply_rolls_back() {
+        let dir = temp_dir("rollback");
+        let module = dir.join("01.rs");
+        fs::write(&module, "old").unwrap();
+        let example = dir.join("examples").join("01.txt");
+
+        // the last step fails, as a file cannot be created inside a file.
+        let steps = vec![
+            Step::new("module file", module.clone(), "new".into(), true),
+            Step::new("example file", example.clone(), String::new(), false),
+            Step::new("input file", module.join("01.txt"), String::new(), false),
+        ];
+
+        let err = apply(&steps).unwrap_err();
+        assert!(err.contains("No files were changed"), "{}", err);
+        assert_eq!(fs::read_to_string(&module).unwrap(), "old");
+        assert!(!example.exists());
+        assert!(!dir.join("examples").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
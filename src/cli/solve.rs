@@ -0,0 +1,21 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `cargo aoc solve <day>`: runs a day's solution in-process, with the options of its binary.
+use pico_args::Arguments;
+
+use crate::registry;
+
+pub fn run(mut args: Arguments) -> super::Result {
+    let day: u8 = args.free_from_fn(crate::parse_day)?;
+
+    match registry::get(day) {
+        Some(solution) => solution.run_cli(args),
+        None => Err(format!(
+            "Day {:02} is not scaffolded. Run \"cargo scaffold {:02}\" to create it.",
+            day, day
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,241 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `cargo aoc status`: shows the progress of all 25 days as a calendar.
+//! Each day is checked for a solution module, a downloaded input, passing example tests and stars.
+//! Stars are the parts with an accepted answer in `answers.json` or a correct submission in `submissions.jsonl`.
+use std::fs;
+
+use pico_args::Arguments;
+
+use crate::answers::{self, Answers};
+use crate::submit::{self, Feedback, Submission};
+use crate::{client, registry, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// Days per row of the calendar.
+const COLUMNS: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Input {
+    Missing,
+    /// Created by `scaffold`, but never downloaded.
+    Empty,
+    Downloaded,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Examples {
+    /// The day is not scaffolded or has no expected answers.
+    Untested,
+    Passed,
+    Failed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DayStatus {
+    day: u8,
+    scaffolded: bool,
+    input: Input,
+    examples: Examples,
+    stars: usize,
+}
+
+impl DayStatus {
+    fn scan(day: u8, answers: &Answers, log: &[Submission]) -> DayStatus {
+        let input = match fs::metadata(crate::input_path("inputs", day)) {
+            Ok(meta) if meta.len() > 0 => Input::Downloaded,
+            Ok(_) => Input::Empty,
+            Err(_) => Input::Missing,
+        };
+
+        let examples = match registry::get(day).map(|solution| solution.test()) {
+            Some(Ok(cases)) if cases > 0 => Examples::Passed,
+            Some(Err(_)) => Examples::Failed,
+            _ => Examples::Untested,
+        };
+
+        DayStatus {
+            day,
+            scaffolded: crate::is_scaffolded(day),
+            input,
+            examples,
+            stars: stars(answers, log, day),
+        }
+    }
+
+    /// Renders a cell of the calendar, e.g. `05 SIE**`. A missing flag is shown as `.`.
+    fn render(&self) -> String {
+        let flag = |set: bool, flag: &str| if set { flag.to_string() } else { ".".into() };
+
+        let examples = match self.examples {
+            Examples::Untested => ".".into(),
+            Examples::Passed => format!("{}E{}", ANSI_GREEN, ANSI_RESET),
+            Examples::Failed => format!("{}x{}", ANSI_RED, ANSI_RESET),
+        };
+
+        format!(
+            "{:02} {}{}{}{}{}",
+            self.day,
+            flag(self.scaffolded, "S"),
+            flag(self.input == Input::Downloaded, "I"),
+            examples,
+            "*".repeat(self.stars),
+            ".".repeat(2 - self.stars)
+        )
+    }
+}
+
+/// Number of parts of `day` that have an accepted answer or were submitted with a correct answer.
+/// Days solved before `cargo submit` logged submissions only have accepted answers.
+fn stars(answers: &Answers, log: &[Submission], day: u8) -> usize {
+    (1..=2)
+        .filter(|part| {
+            answers.get(day, *part).is_some()
+                || log.iter().any(|submission| {
+                    submission.day == day
+                        && submission.part == *part
+                        && submission.feedback == Feedback::Correct
+                })
+        })
+        .count()
+}
+
+/// Renders the calendar with `COLUMNS` days per row.
+fn render_grid(days: &[DayStatus]) -> String {
+    days.chunks(COLUMNS)
+        .map(|row| {
+            let cells: Vec<String> = row.iter().map(DayStatus::render).collect();
+            format!("  {}\n", cells.join("   "))
+        })
+        .collect()
+}
+
+pub fn run(args: Arguments) -> super::Result {
+    super::finish(args)?;
+
+    let answers = Answers::load(&answers::answers_path())
+        .map_err(|e| format!("Failed to load answers: {}", e))?;
+    let log = submit::load_log(&submit::submissions_path())
+        .map_err(|e| format!("Failed to load submissions: {}", e))?;
+
+    let days: Vec<DayStatus> = (1..=25)
+        .map(|day| DayStatus::scan(day, &answers, &log))
+        .collect();
+
+    println!(
+        "🎄 {}Advent of Code {}{} 🎄\n",
+        ANSI_BOLD,
+        client::default_year(),
+        ANSI_RESET
+    );
+    print!("{}", render_grid(&days));
+    println!("\nS: scaffolded, I: input downloaded, E: examples pass (x: fail), *: star");
+
+    let count = |filter: &dyn Fn(&DayStatus) -> bool| days.iter().filter(|day| filter(day)).count();
+
+    println!("---");
+    println!(
+        "🎄 {} day(s) scaffolded, {} input(s) downloaded, {} example test(s) passing, {} ⭐ collected.",
+        count(&|day| day.scaffolded),
+        count(&|day| day.input == Input::Downloaded),
+        count(&|day| day.examples == Examples::Passed),
+        days.iter().map(|day| day.stars).sum::<usize>()
+    );
+
+    let failing: Vec<String> = days
+        .iter()
+        .filter(|day| day.examples == Examples::Failed)
+        .map(|day| format!("{:02}", day.day))
+        .collect();
+
+    if !failing.is_empty() {
+        println!(
+            "{}Example tests fail for day(s) {}.{} Run \"cargo aoc test\" for details.",
+            ANSI_RED,
+            failing.join(", "),
+            ANSI_RESET
+        );
+    }
+
+    let empty: Vec<String> = days
+        .iter()
+        .filter(|day| day.input == Input::Empty)
+        .map(|day| format!("{:02}", day.day))
+        .collect();
+
+    if !empty.is_empty() {
+        println!(
+            "{} empty input file(s) were never downloaded: day(s) {}. Run \"cargo download <day>\" to download them.",
+            empty.len(),
+            empty.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(day: u8, stars: usize) -> DayStatus {
+        DayStatus {
+            day,
+            scaffolded: stars > 0,
+            input: if stars > 0 {
+                Input::Downloaded
+            } else {
+                Input::Missing
+            },
+            examples: Examples::Untested,
+            stars,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(status(3, 2).render(), "03 SI.**");
+        assert_eq!(status(12, 0).render(), "12 .....");
+
+        let failed = DayStatus {
+            examples: Examples::Failed,
+            stars: 1,
+            ..status(5, 0)
+        };
+        assert_eq!(
+            failed.render(),
+            format!("05 ..{}x{}*.", ANSI_RED, ANSI_RESET)
+        );
+    }
+
+    #[test]
+    fn test_stars() {
+        let log = vec![
+            Submission::new(1, 1, "24000", Feedback::Correct),
+            Submission::new(1, 2, "100", Feedback::TooLow),
+            Submission::new(1, 2, "45000", Feedback::Correct),
+            Submission::new(2, 1, "12", Feedback::Incorrect),
+            Submission::new(3, 2, "7", Feedback::Correct),
+        ];
+
+        let mut answers = Answers::default();
+        answers.set(3, 1, "5");
+        answers.set(4, 2, "9");
+
+        assert_eq!(stars(&answers, &log, 1), 2);
+        assert_eq!(stars(&answers, &log, 2), 0);
+        assert_eq!(stars(&answers, &log, 3), 2);
+        assert_eq!(stars(&answers, &log, 4), 1);
+        assert_eq!(stars(&Answers::default(), &[], 1), 0);
+    }
+
+    #[test]
+    fn test_render_grid() {
+        let days: Vec<DayStatus> = (1..=7).map(|day| status(day, 0)).collect();
+        assert_eq!(
+            render_grid(&days),
+            "  01 .....   02 .....   03 .....   04 .....   05 .....\n  06 .....   07 .....\n"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `cargo submit <day> <part>`: solves a part and submits its answer through aoc-cli.
+use std::env;
+use std::process::Command;
+
+use pico_args::Arguments;
+
+use super::Error;
+use crate::answers::{self, Answers};
+use crate::submit::{self, Feedback, Guesses, Submission};
+use crate::{registry, ANSI_BOLD, ANSI_RESET};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<i16>,
+    command: Option<String>,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, Error> {
+    let parsed = Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        command: args.opt_value_from_str("--command")?,
+        day: args.free_from_fn(crate::parse_day)?,
+        part: args.free_from_str()?,
+    };
+
+    super::finish(args)?;
+    Ok(parsed)
+}
+
+/// Runs a part of a day's solution in-process and returns its answer.
+fn solve(day: u8, part: u8) -> Result<String, String> {
+    let solution = registry::get(day).ok_or_else(|| format!("day {} is not scaffolded.", day))?;
+
+    let input = crate::try_read_file("inputs", day)?;
+
+    solution
+        .run(&input)
+        .into_iter()
+        .find(|result| result.part == part)
+        .and_then(|result| result.answer)
+        .ok_or_else(|| format!("day {} part {} is not solved.", day, part))
+}
+
+pub fn run(args: Arguments) -> super::Result {
+    let args = parse_args(args)?;
+
+    if args.part != 1 && args.part != 2 {
+        return Err(Error::Args("part must be 1 or 2.".into()));
+    }
+
+    let answer = solve(args.day, args.part)?;
+
+    let log_path = submit::submissions_path();
+
+    let log =
+        submit::load_log(&log_path).map_err(|e| format!("Failed to load submissions: {}", e))?;
+
+    Guesses::from_log(&log, args.day, args.part)
+        .check(&answer)
+        .map_err(|e| format!("Refusing to submit: {}", e))?;
+
+    // an explicit --command takes precedence over the environment.
+    let command = args
+        .command
+        .or_else(|| env::var(submit::COMMAND_ENV_VAR).ok())
+        .unwrap_or_else(|| submit::DEFAULT_COMMAND.into());
+
+    let mut cmd_args = vec![];
+
+    // without a year, aoc-cli picks the most recent event.
+    if let Some(year) = args.year.or(crate::config::get().year) {
+        cmd_args.push("--year".into());
+        cmd_args.push(year.to_string());
+    }
+
+    cmd_args.append(&mut vec![
+        "--day".into(),
+        args.day.to_string(),
+        "submit".into(),
+        args.part.to_string(),
+        answer.clone(),
+    ]);
+
+    println!("Submitting answer with >{} {}", command, cmd_args.join(" "));
+
+    let output = Command::new(&command)
+        .args(&cmd_args)
+        .output()
+        .map_err(|e| {
+            format!(
+                "failed to spawn \"{}\": {}. Try running \"cargo install aoc-cli\" to install it.",
+                command, e
+            )
+        })?;
+
+    let response = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let feedback = Feedback::parse(&response);
+
+    if feedback == Feedback::Unknown {
+        print!("{}", response);
+    }
+
+    println!("---");
+    println!(
+        "🎄 {}Day {} Part {}{}: {} {}",
+        ANSI_BOLD,
+        args.day,
+        args.part,
+        ANSI_RESET,
+        answer,
+        feedback.describe()
+    );
+
+    // rate limited submissions are logged as well, `Guesses` does not rule out their answers.
+    let submission = Submission::new(args.day, args.part, &answer, feedback);
+    submit::append_log(&log_path, &submission)
+        .map_err(|e| format!("Failed to log submission: {}", e))?;
+
+    if feedback == Feedback::Correct {
+        let answers_path = answers::answers_path();
+        let saved = Answers::load(&answers_path).and_then(|mut answers| {
+            answers.set(args.day, args.part, &answer);
+            answers.save(&answers_path)
+        });
+
+        saved.map_err(|e| format!("Failed to save accepted answer: {}", e))?;
+        Ok(())
+    } else {
+        Err(Error::Failed)
+    }
+}
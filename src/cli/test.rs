@@ -0,0 +1,55 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! `cargo aoc test [days]`: checks solutions against the expected answers in `src/examples/NN.expected`.
+//! Runs the same checks as the `example_tests!` unit tests, but for several days in one go.
+use pico_args::Arguments;
+
+use super::Error;
+use crate::{examples, registry, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+pub fn run(mut args: Arguments) -> super::Result {
+    let days = match args.opt_free_from_fn(crate::parse_days)? {
+        Some(days) => days,
+        None => registry::all()
+            .iter()
+            .map(|solution| solution.day)
+            .collect(),
+    };
+    super::finish(args)?;
+
+    let mut failed = 0;
+
+    for day in days {
+        let solution = match registry::get(day) {
+            Some(solution) => solution,
+            None => {
+                println!("Day {:02}: not scaffolded.", day);
+                continue;
+            }
+        };
+
+        match solution.test() {
+            Ok(0) => println!(
+                "Day {:02}: no expected answers in \"{}\".",
+                day,
+                examples::expected_path(day).display()
+            ),
+            Ok(cases) => println!(
+                "{}Day {:02}: {} example(s) passed.{}",
+                ANSI_GREEN, day, cases, ANSI_RESET
+            ),
+            Err(e) => {
+                failed += 1;
+                println!("{}Day {:02}: {}{}", ANSI_RED, day, e, ANSI_RESET);
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(Error::Failed);
+    }
+
+    Ok(())
+}
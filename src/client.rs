@@ -0,0 +1,250 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Minimal Advent of Code HTTP client used to download inputs and puzzle pages.
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::SystemTime;
+
+use crate::history::utc_date;
+
+/// Environment variable that holds the session cookie. Takes precedence over the session file.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Environment variable that overrides the location of the session file.
+pub const SESSION_FILE_ENV_VAR: &str = "AOC_SESSION_FILE";
+
+/// Name of the session file in the home directory, shared with aoc-cli.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Environment variable that overrides the base url, e.g. to point to a local server in tests.
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn input_url(&self, year: i16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, year: i16, day: u8) -> Result<String, String> {
+        self.get(&self.input_url(year, day))
+    }
+
+    pub fn puzzle_url(&self, year: i16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    /// Fetches the html of a day's puzzle page. Part two is included once part one is solved.
+    pub fn puzzle(&self, year: i16, day: u8) -> Result<String, String> {
+        self.get(&self.puzzle_url(year, day))
+    }
+
+    fn get(&self, url: &str) -> Result<String, String> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(400, _) => {
+                    format!(
+                        "request to {} was rejected, the session cookie is invalid or expired",
+                        url
+                    )
+                }
+                ureq::Error::Status(404, _) => {
+                    format!(
+                        "{} was not found, the puzzle is probably not unlocked yet",
+                        url
+                    )
+                }
+                e => format!("request to {} failed: {}", url, e),
+            })?;
+
+        response
+            .into_string()
+            .map_err(|e| format!("could not read response of {}: {}", url, e))
+    }
+}
+
+/// Base url of the website, overridable with `AOC_BASE_URL`.
+pub fn base_url() -> String {
+    env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into())
+}
+
+/// Location of the session file: `AOC_SESSION_FILE`, `paths.session_file` in `aoc.toml` or `~/.adventofcode.session`.
+pub fn session_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var(SESSION_FILE_ENV_VAR) {
+        return Some(PathBuf::from(path));
+    }
+
+    if let Some(path) = &crate::config::get().session_file {
+        return Some(path.clone());
+    }
+
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// Reads the session cookie from `AOC_SESSION` or the session file at `path`.
+pub fn read_session(path: Option<&Path>) -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_ENV_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = path.ok_or_else(|| {
+        format!(
+            "no session cookie found. Set {} or {}.",
+            SESSION_ENV_VAR, SESSION_FILE_ENV_VAR
+        )
+    })?;
+
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(format!("session file \"{}\" is empty", path.display())),
+        Err(e) => Err(format!(
+            "could not read session file \"{}\": {}",
+            path.display(),
+            e
+        )),
+    }
+}
+
+/// Year of the event: `year` in `aoc.toml` or the most recent event, which is the current year in December
+/// and the year before otherwise.
+pub fn default_year() -> i16 {
+    if let Some(year) = crate::config::get().year {
+        return year;
+    }
+
+    let (year, month, _) = utc_date(SystemTime::now());
+    if month == 12 {
+        year as i16
+    } else {
+        year as i16 - 1
+    }
+}
+
+/// Writes `contents` to a temporary file next to `path` and moves it into place,
+/// so `path` never contains a partial write.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+
+    let mut tmp_name = file_name.to_os_string();
+    tmp_name.push(format!(".{}.tmp", process::id()));
+    let tmp_path = path.with_file_name(tmp_name);
+
+    fs::write(&tmp_path, contents)
+        .and_then(|_| fs::rename(&tmp_path, path))
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&tmp_path);
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single request with `body` and returns the request headers.
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+                request.push('\n');
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, handle) = serve_once("200 OK", "1000\n2000\n");
+        let client = Client::new(&url, "abc123\n");
+
+        assert_eq!(client.input(2022, 1), Ok("1000\n2000\n".into()));
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\n"));
+        assert!(request.contains("Cookie: session=abc123\n"));
+    }
+
+    #[test]
+    fn test_input_rejected() {
+        let (url, handle) = serve_once("400 Bad Request", "");
+        let client = Client::new(&url, "expired");
+
+        let err = client.input(2022, 1).unwrap_err();
+        assert!(err.contains("session cookie is invalid"), "{}", err);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = env::temp_dir().join(format!("aoc_write_atomic_{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("01.txt");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,244 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Project configuration, read from `aoc.toml` in the project directory.
+//!
+//! Every key is optional. Command line flags take precedence over the file, the file over the built-in defaults.
+//! Paths are relative to the project directory, `~/` expands to the home directory.
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use toml::value::Table;
+use toml::Value;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Year of the event. Defaults to the most recent event.
+    pub year: Option<i16>,
+    /// Directory of the puzzle inputs, `src/inputs` by default.
+    pub inputs: PathBuf,
+    /// Directory of the example inputs and their expected answers, `src/examples` by default.
+    pub examples: PathBuf,
+    /// Directory of the downloaded puzzle descriptions, `src/puzzles` by default.
+    pub puzzles: PathBuf,
+    /// Directory of the templates of `cargo scaffold`, `templates` by default.
+    pub templates: PathBuf,
+    /// File holding the session cookie. Defaults to `~/.adventofcode.session`.
+    pub session_file: Option<PathBuf>,
+    pub runner: Runner,
+}
+
+/// Defaults of `cargo all`, `cargo aoc bench` and `cargo solve`, overridden by the flags of the same name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Runner {
+    /// Number of days to run at the same time. Ignored by benchmarks.
+    pub jobs: Option<usize>,
+    /// Time limit of a day in milliseconds. Ignored by benchmarks and `--spawn`.
+    pub timeout: Option<u64>,
+    /// Time limit of a part in milliseconds. Ignored by benchmarks and `--spawn`.
+    pub part_timeout: Option<u64>,
+    /// Time budget of a benchmarked part in milliseconds.
+    pub bench_time: Option<u64>,
+    /// Percentage a benchmark has to change by to be reported by `--compare`.
+    pub threshold: Option<f64>,
+}
+
+impl Config {
+    /// The configuration used without an `aoc.toml` in `dir`.
+    pub fn default_in(dir: &Path) -> Config {
+        Config {
+            year: None,
+            inputs: dir.join("src").join("inputs"),
+            examples: dir.join("src").join("examples"),
+            puzzles: dir.join("src").join("puzzles"),
+            templates: dir.join("templates"),
+            session_file: None,
+            runner: Runner::default(),
+        }
+    }
+
+    /// Parses the contents of an `aoc.toml`, resolving relative paths against `dir`.
+    pub fn parse(contents: &str, dir: &Path) -> Result<Config, String> {
+        let mut table: Table = toml::from_str(contents).map_err(|e| e.to_string())?;
+        let mut config = Config::default_in(dir);
+
+        config.year = take_integer(&mut table, "", "year")?;
+        if let Some(year) = config.year {
+            if year < 2015 {
+                return Err(format!("\"year\" must be 2015 or later, got {}", year));
+            }
+        }
+
+        if let Some(mut paths) = take_table(&mut table, "paths")? {
+            let mut path = |key: &str| {
+                take_string(&mut paths, "paths.", key).map(|path| path.map(|p| resolve(dir, &p)))
+            };
+
+            if let Some(inputs) = path("inputs")? {
+                config.inputs = inputs;
+            }
+            if let Some(examples) = path("examples")? {
+                config.examples = examples;
+            }
+            if let Some(puzzles) = path("puzzles")? {
+                config.puzzles = puzzles;
+            }
+            if let Some(templates) = path("templates")? {
+                config.templates = templates;
+            }
+            config.session_file = path("session_file")?;
+
+            reject_unknown(&paths, "paths.")?;
+        }
+
+        if let Some(mut runner) = take_table(&mut table, "runner")? {
+            config.runner = Runner {
+                jobs: take_integer(&mut runner, "runner.", "jobs")?,
+                timeout: take_integer(&mut runner, "runner.", "timeout")?,
+                part_timeout: take_integer(&mut runner, "runner.", "part_timeout")?,
+                bench_time: take_integer(&mut runner, "runner.", "bench_time")?,
+                threshold: match runner.remove("threshold") {
+                    None => None,
+                    Some(Value::Float(threshold)) => Some(threshold),
+                    Some(Value::Integer(threshold)) => Some(threshold as f64),
+                    Some(_) => return Err("\"runner.threshold\" must be a number".into()),
+                },
+            };
+
+            reject_unknown(&runner, "runner.")?;
+        }
+
+        reject_unknown(&table, "")?;
+
+        Ok(config)
+    }
+
+    /// Reads `aoc.toml` in `dir`. Without the file, the defaults are used.
+    pub fn load(dir: &Path) -> Result<Config, String> {
+        let path = dir.join(CONFIG_FILE);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => Config::parse(&contents, dir)
+                .map_err(|e| format!("invalid config \"{}\": {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default_in(dir)),
+            Err(e) => Err(format!("could not read \"{}\": {}", path.display(), e)),
+        }
+    }
+}
+
+fn take_table(table: &mut Table, key: &str) -> Result<Option<Table>, String> {
+    match table.remove(key) {
+        None => Ok(None),
+        Some(Value::Table(table)) => Ok(Some(table)),
+        Some(_) => Err(format!("\"{}\" must be a table", key)),
+    }
+}
+
+fn take_string(table: &mut Table, prefix: &str, key: &str) -> Result<Option<String>, String> {
+    match table.remove(key) {
+        None => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(_) => Err(format!("\"{}{}\" must be a string", prefix, key)),
+    }
+}
+
+fn take_integer<T: TryFrom<i64>>(
+    table: &mut Table,
+    prefix: &str,
+    key: &str,
+) -> Result<Option<T>, String> {
+    match table.remove(key) {
+        None => Ok(None),
+        Some(Value::Integer(value)) => T::try_from(value)
+            .map(Some)
+            .map_err(|_| format!("\"{}{}\" is out of range: {}", prefix, key, value)),
+        Some(_) => Err(format!("\"{}{}\" must be an integer", prefix, key)),
+    }
+}
+
+/// Fails on keys that were not taken, which are most likely typos.
+fn reject_unknown(table: &Table, prefix: &str) -> Result<(), String> {
+    match table.keys().next() {
+        Some(key) => Err(format!("unknown key \"{}{}\"", prefix, key)),
+        None => Ok(()),
+    }
+}
+
+fn resolve(dir: &Path, path: &str) -> PathBuf {
+    if let Some(rest) = path.strip_prefix("~/") {
+        if let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
+            return PathBuf::from(home).join(rest);
+        }
+    }
+
+    dir.join(path)
+}
+
+static CONFIG: OnceLock<Result<Config, String>> = OnceLock::new();
+
+/// The configuration of this project, read once from `aoc.toml` in the project directory.
+pub fn load() -> Result<&'static Config, String> {
+    CONFIG
+        .get_or_init(|| Config::load(&crate::project_dir()))
+        .as_ref()
+        .map_err(|e| e.clone())
+}
+
+/// Like `load`, but panics if `aoc.toml` is invalid.
+/// The command line entry points call `load` first to report an invalid config.
+pub fn get() -> &'static Config {
+    load().unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let dir = Path::new("/aoc");
+        let config = Config::parse(
+            "year = 2022\n\
+            [paths]\n\
+            inputs = \"data/inputs\"\n\
+            session_file = \"/etc/aoc.session\"\n\
+            [runner]\n\
+            jobs = 4\n\
+            timeout = 10000\n\
+            threshold = 5\n",
+            dir,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2022));
+        assert_eq!(config.inputs, PathBuf::from("/aoc/data/inputs"));
+        assert_eq!(config.examples, PathBuf::from("/aoc/src/examples"));
+        assert_eq!(config.session_file, Some(PathBuf::from("/etc/aoc.session")));
+        assert_eq!(config.runner.jobs, Some(4));
+        assert_eq!(config.runner.timeout, Some(10000));
+        assert_eq!(config.runner.part_timeout, None);
+        assert_eq!(config.runner.threshold, Some(5.0));
+
+        assert_eq!(Config::parse("", dir), Ok(Config::default_in(dir)));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let dir = Path::new("/aoc");
+        assert!(Config::parse("year = \"2022\"", dir).is_err());
+        assert!(Config::parse("year = 1999", dir).is_err());
+        assert_eq!(
+            Config::parse("[runner]\njobs = -1", dir),
+            Err("\"runner.jobs\" is out of range: -1".into())
+        );
+        assert_eq!(
+            Config::parse("[paths]\ninput = \"inputs\"", dir),
+            Err("unknown key \"paths.input\"".into())
+        );
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Expected answers for the example inputs, stored in `src/examples/NN.expected`.
+//!
+//! Each line holds the answer of a part for the day's example (`1 = 24000`)
+//! or for a named example (`a:2 = 45000` for `src/examples/NN-a.txt`).
+//! Multi-line answers are written with `\n` escapes. Lines starting with `#` are comments.
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::panics;
+use crate::solution::{IntoOutcome, Outcome, Solution};
+
+/// Expected answer of a part for one example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Case {
+    /// Name of the example file, `None` for the day's main example.
+    pub example: Option<String>,
+    pub part: u8,
+    pub answer: String,
+}
+
+impl Case {
+    /// Name of the example file the case refers to, e.g. `01.txt` or `01-a.txt`.
+    pub fn file_name(&self, day: u8) -> String {
+        match &self.example {
+            Some(name) => format!("{:02}-{}.txt", day, name),
+            None => format!("{:02}.txt", day),
+        }
+    }
+
+    /// Reads the case's example input. Cases for the main example use the part-specific file if there is one.
+    pub fn read_input(&self, day: u8) -> String {
+        match &self.example {
+            Some(name) => crate::read_file_named("examples", day, name),
+            None => crate::read_file_part("examples", day, self.part),
+        }
+    }
+}
+
+pub fn expected_path(day: u8) -> PathBuf {
+    crate::folder_path("examples").join(format!("{:02}.expected", day))
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Case>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            let invalid = || format!("line {} is not valid: \"{}\"", i + 1, line);
+
+            let (key, answer) = line.split_once('=').ok_or_else(invalid)?;
+            let (example, part) = match key.trim().split_once(':') {
+                Some((example, part)) => (Some(example.trim().to_string()), part),
+                None => (None, key),
+            };
+
+            match part.trim().parse::<u8>() {
+                Ok(part @ 1..=2) => Ok(Case {
+                    example,
+                    part,
+                    answer: answer.trim().replace("\\n", "\n"),
+                }),
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+/// Loads the expected answers of a day. A missing file yields no cases.
+pub fn load(day: u8) -> Result<Vec<Case>, String> {
+    let path = expected_path(day);
+    match fs::read_to_string(&path) {
+        Ok(contents) => parse(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(format!("could not read \"{}\": {}", path.display(), e)),
+    }
+}
+
+/// Solves a part of an example. Panics are reported like errors, so all cases are checked.
+fn solve<S: Solution>(input: &str, part: u8) -> Outcome {
+    panics::catch(|| {
+        let parsed = S::parse(input);
+        match part {
+            1 => S::part_one(&parsed).into_outcome(),
+            _ => S::part_two(&parsed).into_outcome(),
+        }
+    })
+    .and_then(|outcome| outcome)
+}
+
+/// Runs every case listed in the day's expected answers.
+/// Returns the number of cases, or a message listing all mismatches.
+pub fn verify<S: Solution>() -> Result<usize, String> {
+    let cases = load(S::DAY)?;
+
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|case| {
+            let outcome = panics::catch(|| case.read_input(S::DAY))
+                .and_then(|input| solve::<S>(&input, case.part));
+
+            let got = match outcome {
+                Ok(Some(answer)) if answer == case.answer => return None,
+                Ok(answer) => format!("{:?}", answer),
+                Err(e) => format!("error \"{}\"", e),
+            };
+
+            Some(format!(
+                "{} part {}: expected {:?}, got {}",
+                case.file_name(S::DAY),
+                case.part,
+                case.answer,
+                got
+            ))
+        })
+        .collect();
+
+    if failures.is_empty() {
+        Ok(cases.len())
+    } else {
+        Err(format!(
+            "{} of {} example(s) failed:\n{}",
+            failures.len(),
+            cases.len(),
+            failures.join("\n")
+        ))
+    }
+}
+
+/// Runs every case listed in the day's expected answers and panics with all mismatches.
+pub fn check<S: Solution>() {
+    if let Err(e) = verify::<S>() {
+        panic!("{}", e);
+    }
+}
+
+/// Generates a test that checks a solution against all answers listed in `src/examples/NN.expected`.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_examples() {
+            advent_of_code::examples::check::<$solution>();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let cases = parse("# comment\n1 = 24000\n\na:2 = ##..\\n..##\n").unwrap();
+
+        assert_eq!(
+            cases,
+            vec![
+                Case {
+                    example: None,
+                    part: 1,
+                    answer: "24000".into()
+                },
+                Case {
+                    example: Some("a".into()),
+                    part: 2,
+                    answer: "##..\n..##".into()
+                },
+            ]
+        );
+        assert_eq!(cases[1].file_name(5), "05-a.txt");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("1 24000").is_err());
+        assert!(parse("3 = 24000").is_err());
+        assert!(parse("a:x = 1").is_err());
+    }
+}
@@ -0,0 +1,346 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Benchmark history stored in `benchmarks.json`, used to detect performance regressions.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tinyjson::JsonValue;
+
+use crate::bench::BenchResult;
+
+pub const HISTORY_FILE: &str = "benchmarks.json";
+
+/// Location of the benchmark history in the project directory.
+pub fn history_path() -> PathBuf {
+    crate::project_dir().join(HISTORY_FILE)
+}
+
+/// Default slowdown (in percent) of a median timing that counts as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Benchmark timings of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+/// A recorded benchmark run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Run {
+    pub commit: String,
+    pub date: String,
+    pub records: Vec<Record>,
+}
+
+impl Run {
+    /// Creates a run for the current git commit and time from benchmark results. Unsolved parts are skipped.
+    pub fn from_results(results: &[BenchResult]) -> Run {
+        let records = results
+            .iter()
+            .filter_map(|result| {
+                let stats = result.stats.as_ref()?;
+                Some(Record {
+                    day: result.day,
+                    part: result.part,
+                    min: stats.min,
+                    median: stats.median,
+                    mean: stats.mean,
+                    stddev: stats.stddev,
+                })
+            })
+            .collect();
+
+        Run {
+            commit: git_commit(),
+            date: utc_timestamp(SystemTime::now()),
+            records,
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Record> {
+        self.records
+            .iter()
+            .find(|record| record.day == day && record.part == part)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Loads the history from `path`. A missing file yields an empty history.
+    pub fn load(path: &Path) -> Result<History, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => History::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(format!("could not read \"{}\": {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_json())
+            .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
+    }
+
+    /// The most recently recorded run.
+    pub fn last(&self) -> Option<&Run> {
+        self.runs.last()
+    }
+
+    pub fn parse(contents: &str) -> Result<History, String> {
+        let invalid = || "benchmark history is not valid".to_string();
+
+        let value: JsonValue = contents.parse().map_err(|_| invalid())?;
+        let object: &HashMap<String, JsonValue> = value.get().ok_or_else(invalid)?;
+        let runs: &Vec<JsonValue> = object
+            .get("runs")
+            .and_then(|runs| runs.get())
+            .ok_or_else(invalid)?;
+
+        let runs = runs
+            .iter()
+            .map(parse_run)
+            .collect::<Option<Vec<Run>>>()
+            .ok_or_else(invalid)?;
+
+        Ok(History { runs })
+    }
+
+    pub fn to_json(&self) -> String {
+        let runs = self
+            .runs
+            .iter()
+            .map(|run| {
+                let records = run
+                    .records
+                    .iter()
+                    .map(|record| {
+                        format!(
+                            r#"        {{"day":{},"part":{},"min_ns":{},"median_ns":{},"mean_ns":{},"stddev_ns":{}}}"#,
+                            record.day,
+                            record.part,
+                            record.min.as_nanos(),
+                            record.median.as_nanos(),
+                            record.mean.as_nanos(),
+                            record.stddev.as_nanos()
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",\n");
+
+                format!(
+                    "    {{\n      \"commit\": {},\n      \"date\": {},\n      \"results\": [\n{}\n      ]\n    }}",
+                    JsonValue::String(run.commit.clone()).stringify().unwrap(),
+                    JsonValue::String(run.date.clone()).stringify().unwrap(),
+                    records
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",\n");
+
+        format!("{{\n  \"runs\": [\n{}\n  ]\n}}\n", runs)
+    }
+}
+
+fn parse_run(value: &JsonValue) -> Option<Run> {
+    let object: &HashMap<String, JsonValue> = value.get()?;
+    let records: &Vec<JsonValue> = object.get("results")?.get()?;
+
+    Some(Run {
+        commit: object.get("commit")?.get::<String>()?.clone(),
+        date: object.get("date")?.get::<String>()?.clone(),
+        records: records.iter().map(parse_record).collect::<Option<_>>()?,
+    })
+}
+
+fn parse_record(value: &JsonValue) -> Option<Record> {
+    let object: &HashMap<String, JsonValue> = value.get()?;
+    let number = |key: &str| -> Option<f64> { object.get(key)?.get::<f64>().copied() };
+    let duration =
+        |key: &str| -> Option<Duration> { Some(Duration::from_nanos(number(key)? as u64)) };
+
+    Some(Record {
+        day: number("day")? as u8,
+        part: number("part")? as u8,
+        min: duration("min_ns")?,
+        median: duration("median_ns")?,
+        mean: duration("mean_ns")?,
+        stddev: duration("stddev_ns")?,
+    })
+}
+
+/// Median timing of a part in a baseline run compared to the current run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change of the median in percent. Positive values mean slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Compares the medians of all parts present in both runs.
+/// A part regressed if its median got slower by more than `threshold` percent.
+pub fn compare(baseline: &Run, current: &Run, threshold: f64) -> Vec<Comparison> {
+    current
+        .records
+        .iter()
+        .filter_map(|record| {
+            let base = baseline.get(record.day, record.part)?;
+            let base_nanos = base.median.as_nanos() as f64;
+            let change = if base_nanos > 0_f64 {
+                (record.median.as_nanos() as f64 - base_nanos) / base_nanos * 100_f64
+            } else {
+                0_f64
+            };
+
+            Some(Comparison {
+                day: record.day,
+                part: record.part,
+                baseline: base.median,
+                current: record.median,
+                change,
+                regressed: change > threshold,
+            })
+        })
+        .collect()
+}
+
+/// Short hash of the checked-out git commit, suffixed with `-dirty` if there are uncommitted changes.
+fn git_commit() -> String {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git").args(args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".into(),
+    }
+}
+
+/// Formats a point in time as an RFC 3339 UTC timestamp, e.g. `2022-12-11T06:00:00Z`.
+pub(crate) fn utc_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    let (days, secs_of_day) = (secs.div_euclid(86400), secs.rem_euclid(86400));
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// UTC calendar date (year, month, day) of a point in time.
+pub(crate) fn utc_date(time: SystemTime) -> (i64, i64, i64) {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    civil_from_days(secs.div_euclid(86400))
+}
+
+/// Converts days since the unix epoch to a (year, month, day) date.
+/// See: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: u8, median_us: u64) -> Record {
+        Record {
+            day,
+            part,
+            min: Duration::from_micros(median_us / 2),
+            median: Duration::from_micros(median_us),
+            mean: Duration::from_micros(median_us),
+            stddev: Duration::from_nanos(1),
+        }
+    }
+
+    fn run(records: Vec<Record>) -> Run {
+        Run {
+            commit: "abc1234".into(),
+            date: "2022-12-11T06:00:00Z".into(),
+            records,
+        }
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let history = History {
+            runs: vec![run(vec![record(1, 1, 10), record(1, 2, 20)]), run(vec![])],
+        };
+
+        assert_eq!(History::parse(&history.to_json()), Ok(history));
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = run(vec![
+            record(1, 1, 100),
+            record(1, 2, 100),
+            record(2, 1, 100),
+        ]);
+        let current = run(vec![
+            record(1, 1, 105),
+            record(1, 2, 150),
+            record(3, 1, 100),
+        ]);
+
+        let comparisons = compare(&baseline, &current, 10.0);
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!((comparisons[0].change - 5.0).abs() < 1.0e-6);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change - 50.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_utc_timestamp() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_670_738_400);
+        assert_eq!(utc_timestamp(time), "2022-12-11T06:00:00Z");
+        assert_eq!(utc_timestamp(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+    }
+}
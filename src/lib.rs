@@ -5,90 +5,186 @@
  */
 use std::env;
 use std::fs;
-
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// allows day modules to refer to `advent_of_code::` when compiled into the registry.
+extern crate self as advent_of_code;
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod examples;
 pub mod helpers;
+pub mod history;
+pub mod memory;
+pub mod panics;
+pub mod puzzle;
+pub mod readme;
+pub mod registry;
+pub mod report;
+pub mod solution;
+pub mod submit;
+pub mod template;
+pub mod watchdog;
+
+pub use report::PartResult;
+pub use solution::Solution;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+/// Environment variable that overrides the location of a day's puzzle input. `-` reads from stdin.
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+
+/// Root directory of the project, independent of the directory a binary or test is run from.
+pub fn project_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Directory of a kind of day files. `inputs`, `examples` and `puzzles` can be moved in `aoc.toml`,
+/// other folders are looked up in `src`.
+pub fn folder_path(folder: &str) -> PathBuf {
+    let config = config::get();
+
+    match folder {
+        "inputs" => config.inputs.clone(),
+        "examples" => config.examples.clone(),
+        "puzzles" => config.puzzles.clone(),
+        _ => project_dir().join("src").join(folder),
+    }
+}
+
+pub fn input_path(folder: &str, day: u8) -> PathBuf {
+    folder_path(folder).join(format!("{:02}.txt", day))
+}
+
+/// Path of the solution module of a day, e.g. `src/bin/07.rs`.
+pub fn module_path(day: u8) -> PathBuf {
+    project_dir()
+        .join("src")
+        .join("bin")
+        .join(format!("{:02}.rs", day))
+}
+
+/// Whether `cargo scaffold` created a solution module for this day.
+pub fn is_scaffolded(day: u8) -> bool {
+    module_path(day).is_file()
+}
+
+/// Reads a day's file of a folder. A missing or empty puzzle input is reported with a hint to download it.
+fn read_path(path: &Path, folder: &str, day: u8) -> Result<String, String> {
+    let download_hint = || {
+        format!(
+            "Run \"cargo download {:02}\" to download it, or pass its location with --input <path>.",
+            day
+        )
+    };
+
+    match fs::read_to_string(path) {
+        Ok(contents) if folder == "inputs" && contents.trim().is_empty() => Err(format!(
+            "input file \"{}\" is empty. {}",
+            path.display(),
+            download_hint()
+        )),
+        Ok(contents) => Ok(contents),
+        Err(e) if folder == "inputs" && e.kind() == io::ErrorKind::NotFound => Err(format!(
+            "input file \"{}\" does not exist. {}",
+            path.display(),
+            download_hint()
+        )),
+        Err(e) => Err(format!("could not open \"{}\": {}", path.display(), e)),
+    }
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Reads a day's file of a folder, e.g. `src/inputs/05.txt` for `inputs`. See `folder_path`.
+pub fn try_read_file(folder: &str, day: u8) -> Result<String, String> {
+    read_path(&input_path(folder, day), folder, day)
 }
 
+/// Like `try_read_file`, but panics if the file cannot be read.
 pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+    try_read_file(folder, day).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Reads the puzzle input of a day from `path`, the location in `AOC_INPUT` or `NN.txt` in the inputs directory, in that order.
+/// A location of `-` reads the input from stdin.
+pub fn read_input(day: u8, path: Option<&str>) -> Result<String, String> {
+    let path = path
+        .map(|path| path.to_string())
+        .or_else(|| env::var(INPUT_ENV_VAR).ok().filter(|path| !path.is_empty()));
+
+    match path.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("could not read input from stdin: {}", e))?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| format!("could not open input file \"{}\": {}", path, e)),
+        None => try_read_file("inputs", day),
+    }
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Path of a named variant of a day's file, e.g. `src/examples/05-2.txt` or `src/examples/05-a.txt`.
+pub fn named_input_path(folder: &str, day: u8, name: &str) -> PathBuf {
+    folder_path(folder).join(format!("{:02}-{}.txt", day, name))
+}
+
+/// Reads a named variant of a day's file, e.g. `read_file_named("examples", 5, "a")` reads `05-a.txt`.
+pub fn read_file_named(folder: &str, day: u8, name: &str) -> String {
+    read_path(&named_input_path(folder, day, name), folder, day).unwrap_or_else(|e| panic!("{}", e))
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Reads the file of a day for a specific part: `NN-<part>.txt` if it exists, `NN.txt` otherwise.
+/// Useful for puzzles that use different examples for both parts.
+pub fn read_file_part(folder: &str, day: u8, part: u8) -> String {
+    let path = named_input_path(folder, day, &part.to_string());
+    if path.is_file() {
+        read_file_named(folder, day, &part.to_string())
+    } else {
+        read_file(folder, day)
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Parses a day between 1 and 25.
+pub fn parse_day(val: &str) -> Result<u8, String> {
+    match val.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", val.trim())),
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
+/// Parses a selection of days: a single day (`3`), a range (`1..=5`, `1..5`) or a list (`7,9,11`).
+/// Lists can contain ranges (`1..=3,7`). Returns the selected days in ascending order.
+pub fn parse_days(selection: &str) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+
+    for item in selection.split(',') {
+        if let Some((start, end)) = item.split_once("..=") {
+            days.extend(parse_day(start)?..=parse_day(end)?);
+        } else if let Some((start, end)) = item.split_once("..") {
+            days.extend(parse_day(start)?..parse_day(end)?);
         } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+            days.push(parse_day(item)?);
         }
-    })
-}
+    }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+    days.sort_unstable();
+    days.dedup();
+
+    if days.is_empty() {
+        return Err(format!("\"{}\" does not select any day", selection));
+    }
+
+    Ok(days)
 }
 
 #[cfg(test)]
@@ -96,30 +192,37 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    fn test_try_read_file() {
+        assert!(try_read_file("examples", 1).is_ok());
+
+        let err = try_read_file("inputs", 26).unwrap_err();
+        assert!(err.contains("does not exist"), "{}", err);
+        assert!(err.contains("cargo download 26"), "{}", err);
+    }
+
+    #[test]
+    fn test_read_file_part() {
+        assert!(named_input_path("examples", 5, "2").ends_with("src/examples/05-2.txt"));
+        // without a part-specific file, the day's file is used.
+        assert_eq!(read_file_part("examples", 1, 2), read_file("examples", 1));
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3"), Ok(vec![3]));
+        assert_eq!(parse_days("1..=5"), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(parse_days("1..5"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(parse_days("11,7,9"), Ok(vec![7, 9, 11]));
+        assert_eq!(parse_days("1..=3, 2,25"), Ok(vec![1, 2, 3, 25]));
+    }
+
+    #[test]
+    fn test_parse_days_invalid() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("1..=26").is_err());
+        assert!(parse_days("five").is_err());
+        assert!(parse_days("5..5").is_err());
+        assert!(parse_days("").is_err());
     }
 }
@@ -0,0 +1,179 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Opt-in allocation tracking, enabled with the `memory` feature (`cargo solve 08 --features memory`).
+//!
+//! The feature installs a global allocator that counts the allocations of the current thread.
+//! Allocations of threads spawned by a solution are not attributed to it.
+
+/// Allocations made while running a solver.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested by those allocations.
+    pub allocated_bytes: u64,
+    /// Highest number of bytes that were live at the same time, above what was live before.
+    pub peak_bytes: u64,
+}
+
+impl MemoryStats {
+    /// Renders the stats in the style of elapsed times, e.g. `allocations: 12, allocated: 1.50 KiB, peak: 512 B`.
+    pub fn to_human(&self) -> String {
+        format!(
+            "allocations: {}, allocated: {}, peak: {}",
+            self.allocations,
+            format_bytes(self.allocated_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+/// Whether the crate was built with the `memory` feature.
+pub fn enabled() -> bool {
+    cfg!(feature = "memory")
+}
+
+/// Runs `func` and records the allocations it makes on the current thread.
+/// Without the `memory` feature, no stats are recorded.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    #[cfg(feature = "memory")]
+    {
+        let start = counting::snapshot();
+        let result = func();
+        (result, Some(counting::since(start)))
+    }
+
+    #[cfg(not(feature = "memory"))]
+    (func(), None)
+}
+
+#[cfg(feature = "memory")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    use super::MemoryStats;
+
+    /// Wraps the system allocator and counts the allocations of each thread.
+    pub struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    // const-initialized without destructors, so they can be used from within the allocator.
+    thread_local! {
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+        static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+        static LIVE: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+    }
+
+    fn record_alloc(size: usize) {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        let _ = ALLOCATED.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + size as i64);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+    }
+
+    fn record_dealloc(size: usize) {
+        let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record_alloc(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record_alloc(layout.size());
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            record_dealloc(layout.size());
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    /// Counters of the current thread at the start of a measurement.
+    pub struct Snapshot {
+        allocations: u64,
+        allocated: u64,
+        live: i64,
+    }
+
+    pub fn snapshot() -> Snapshot {
+        let live = LIVE.get();
+        // peaks are measured relative to what is live when the measurement starts.
+        PEAK.set(live);
+
+        Snapshot {
+            allocations: ALLOCATIONS.get(),
+            allocated: ALLOCATED.get(),
+            live,
+        }
+    }
+
+    pub fn since(start: Snapshot) -> MemoryStats {
+        MemoryStats {
+            allocations: ALLOCATIONS.get() - start.allocations,
+            allocated_bytes: ALLOCATED.get() - start.allocated,
+            peak_bytes: (PEAK.get() - start.live).max(0) as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| vec![0_u8; 4096].len());
+        assert_eq!(len, 4096);
+
+        match stats {
+            Some(stats) => {
+                assert_eq!(stats.allocations, 1);
+                assert_eq!(stats.allocated_bytes, 4096);
+                assert_eq!(stats.peak_bytes, 4096);
+            }
+            None => assert!(!enabled()),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Catches panics of solvers, so a panicking part does not take down the other part or other days.
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    /// Whether the current thread is inside `catch`. Panics elsewhere are reported by the default hook.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Message and location of the last panic caught on the current thread.
+    static MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Strips the project directory from a source path.
+/// Days compiled into the solution registry are included by their absolute path.
+fn relative_path(file: &str) -> &str {
+    file.strip_prefix(concat!(env!("CARGO_MANIFEST_DIR"), "/"))
+        .unwrap_or(file)
+}
+
+/// Installs a panic hook that records caught panics instead of printing them.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.get() {
+                default_hook(info);
+                return;
+            }
+
+            let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+            let message = match info.location() {
+                Some(location) => format!(
+                    "panicked at {}:{}:{}: {}",
+                    relative_path(location.file()),
+                    location.line(),
+                    location.column(),
+                    message
+                ),
+                None => format!("panicked: {}", message),
+            };
+
+            MESSAGE.set(Some(message));
+        }));
+    });
+}
+
+/// Runs `func`, turning a panic into an error that holds the panic message and location,
+/// e.g. `panicked at src/bin/06.rs:37:20: index out of bounds: the len is 4 but the index is 4`.
+pub fn catch<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING.set(was_catching);
+
+    result.map_err(|_| MESSAGE.take().unwrap_or_else(|| "panicked".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 42), Ok(42));
+
+        let err = catch(|| -> u32 { panic!("no marker in {:?}", "abc") }).unwrap_err();
+        assert!(err.starts_with("panicked at src/panics.rs:"), "{}", err);
+        assert!(err.ends_with(": no marker in \"abc\""), "{}", err);
+
+        // panics are caught independently of each other.
+        assert_eq!(catch(|| "ok"), Ok("ok"));
+    }
+}
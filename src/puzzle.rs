@@ -0,0 +1,236 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Converts puzzle pages to markdown and extracts their examples.
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits html into tags and text. Comments and doctypes are skipped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let start = match rest.find('<') {
+            Some(start) => start,
+            None => {
+                tokens.push(Token::Text(rest));
+                break;
+            }
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') {
+            let tag = tag.trim_end_matches('/');
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+    }
+
+    tokens
+}
+
+/// Reads the value of an attribute, e.g. `href` from `href="/2022/day/1/input"`.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = attrs[start..].find('"')?;
+    Some(&attrs[start..start + len])
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Html inside the `<article>` elements of a puzzle page, one per unlocked part.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let body = &rest[start..];
+        let body_start = match body.find('>') {
+            Some(i) => i + 1,
+            None => break,
+        };
+        let body_end = body.find("</article>").unwrap_or(body.len());
+
+        articles.push(&body[body_start..body_end.max(body_start)]);
+        rest = &body[body_end.max(body_start)..];
+    }
+
+    articles
+}
+
+/// Terminates a markdown block with a single blank line.
+fn end_block(out: &mut String) {
+    let len = out.trim_end().len();
+    out.truncate(len);
+    if !out.is_empty() {
+        out.push_str("\n\n");
+    }
+}
+
+/// Converts the puzzle descriptions of a puzzle page to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<&str> = vec![];
+    let mut in_pre = false;
+    let mut in_code = false;
+
+    for article in articles(html) {
+        for token in tokenize(article) {
+            match token {
+                Token::Open("h2", _) => out.push_str("## "),
+                Token::Close("h2") | Token::Close("p") | Token::Close("ul") => end_block(&mut out),
+                Token::Open("pre", _) => {
+                    end_block(&mut out);
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                Token::Close("pre") => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```");
+                    end_block(&mut out);
+                }
+                Token::Open("code", _) if !in_pre => {
+                    in_code = true;
+                    out.push('`');
+                }
+                Token::Close("code") if !in_pre => {
+                    in_code = false;
+                    out.push('`');
+                }
+                Token::Open("em", _) | Token::Close("em") if !in_pre && !in_code => {
+                    out.push_str("**")
+                }
+                Token::Open("li", _) => out.push_str("- "),
+                Token::Close("li") => out.push('\n'),
+                Token::Open("br", _) => out.push('\n'),
+                Token::Open("a", attrs) => {
+                    links.push(attribute(attrs, "href").unwrap_or(""));
+                    out.push('[');
+                }
+                Token::Close("a") => {
+                    out.push_str(&format!("]({})", links.pop().unwrap_or("")));
+                }
+                // skip the whitespace between block elements.
+                Token::Text(text) if !in_pre && text.trim().is_empty() && out.ends_with('\n') => {}
+                Token::Text(text) => out.push_str(&unescape(text)),
+                _ => {}
+            }
+        }
+
+        end_block(&mut out);
+    }
+
+    format!("{}\n", out.trim_end())
+}
+
+/// Extracts the contents of all `<pre><code>` blocks of the puzzle descriptions.
+/// These are candidates for the example input of a day.
+pub fn examples(html: &str) -> Vec<String> {
+    let mut examples = vec![];
+    let mut current: Option<String> = None;
+
+    for article in articles(html) {
+        for token in tokenize(article) {
+            match (token, current.as_mut()) {
+                (Token::Open("pre", _), _) => current = Some(String::new()),
+                (Token::Close("pre"), Some(_)) => examples.extend(current.take()),
+                (Token::Text(text), Some(example)) => example.push_str(&unescape(text)),
+                _ => {}
+            }
+        }
+    }
+
+    examples
+}
+
+/// Extracts the title of a puzzle from its markdown description, e.g. `Day 1: Calorie Counting`.
+pub fn title(markdown: &str) -> Option<String> {
+    markdown
+        .lines()
+        .filter_map(|line| line.strip_prefix("## --- "))
+        .filter_map(|line| line.strip_suffix(" ---"))
+        .find(|title| title.starts_with("Day "))
+        .map(|title| title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <a href="/2022/about" target="_blank">Calories</a>.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>3000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code><em>6000</em></code> Calories.</li>
+</ul>
+<p>Find the Elf carrying the <em>most Calories</em> &amp; <code>a &lt; b</code>.</p>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><pre><code>x</code></pre></article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE),
+            "## --- Day 1: Calorie Counting ---\n\n\
+            The Elves take turns writing down the number of [Calories](/2022/about).\n\n\
+            For example:\n\n\
+            ```\n1000\n2000\n\n3000\n```\n\n\
+            - The first Elf is carrying `6000` Calories.\n\n\
+            Find the Elf carrying the **most Calories** & `a < b`.\n\n\
+            ## --- Part Two ---\n\n```\nx\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(examples(PAGE), vec!["1000\n2000\n\n3000\n", "x"]);
+        assert!(examples("<p>no puzzle</p>").is_empty());
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(
+            title(&to_markdown(PAGE)),
+            Some("Day 1: Calorie Counting".into())
+        );
+        assert_eq!(title("## --- Part Two ---\n"), None);
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Keeps a table of solution timings in the readme up to date.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::report::PartResult;
+
+pub const README_FILE: &str = "README.md";
+
+/// Location of the readme in the project directory.
+pub fn readme_path() -> PathBuf {
+    crate::project_dir().join(README_FILE)
+}
+
+/// Marks the start and the end of the generated section in the readme.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+/// Renders a markdown table with one row per day that has at least one result.
+pub fn render_table(results: &[PartResult]) -> String {
+    let mut days: Vec<u8> = results.iter().map(|result| result.day).collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let cell = |day: u8, part: u8| -> String {
+        results
+            .iter()
+            .find(|result| result.day == day && result.part == part && result.is_solved())
+            .map_or("-".into(), |result| format!("⭐ `{:.2?}`", result.elapsed))
+    };
+
+    let rows = days
+        .iter()
+        .map(|day| {
+            format!(
+                "| [Day {}](./src/bin/{:02}.rs) | {} | {} |\n",
+                day,
+                day,
+                cell(*day, 1),
+                cell(*day, 2)
+            )
+        })
+        .collect::<String>();
+
+    let total: Duration = results
+        .iter()
+        .filter(|result| result.is_solved())
+        .map(|result| result.elapsed)
+        .sum();
+
+    let stars = results.iter().filter(|result| result.is_solved()).count();
+
+    format!(
+        "## Benchmarks\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n{}\n**Total: {:.2}ms** ({} ⭐)\n",
+        rows,
+        total.as_secs_f64() * 1000_f64,
+        stars
+    )
+}
+
+/// Replaces the text between the two markers in `contents` with `table`.
+pub fn replace_section(contents: &str, table: &str) -> Result<String, String> {
+    let start = contents.find(MARKER).ok_or_else(missing_markers)?;
+    let content_start = start + MARKER.len();
+    let end = contents[content_start..]
+        .find(MARKER)
+        .ok_or_else(missing_markers)?
+        + content_start;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &contents[..content_start],
+        table,
+        &contents[end..]
+    ))
+}
+
+fn missing_markers() -> String {
+    format!(
+        "readme does not contain a section delimited by two \"{}\" markers",
+        MARKER
+    )
+}
+
+/// Replaces the `{year}` placeholder of the template's title with the configured year.
+pub fn fill_title(contents: &str, year: Option<i16>) -> String {
+    match year {
+        Some(year) => contents.replacen(
+            "# 🎄 Advent of Code {year}",
+            &format!("# 🎄 Advent of Code {}", year),
+            1,
+        ),
+        None => contents.to_string(),
+    }
+}
+
+/// Rewrites the timing table in the readme at `path` and fills in the year of its title.
+pub fn update(path: &Path, results: &[PartResult], year: Option<i16>) -> Result<(), String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("could not read \"{}\": {}", path.display(), e))?;
+    let updated = replace_section(&fill_title(&contents, year), &render_table(results))?;
+    fs::write(path, updated).map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table() {
+        let results = vec![
+            PartResult::new(2, 1, Some("1".into()), Duration::from_micros(500)),
+            PartResult::new(2, 2, None, Duration::from_micros(0)),
+            PartResult::new(1, 1, Some("24000".into()), Duration::from_micros(1000)),
+            PartResult::new(1, 2, Some("45000".into()), Duration::from_micros(1500)),
+        ];
+
+        assert_eq!(
+            render_table(&results),
+            "## Benchmarks\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+            | [Day 1](./src/bin/01.rs) | ⭐ `1.00ms` | ⭐ `1.50ms` |\n\
+            | [Day 2](./src/bin/02.rs) | ⭐ `500.00µs` | - |\n\
+            \n**Total: 3.00ms** (3 ⭐)\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let contents = format!("# Title\n\n{}\nold table\n{}\n\n## Usage\n", MARKER, MARKER);
+        assert_eq!(
+            replace_section(&contents, "new table\n"),
+            Ok(format!(
+                "# Title\n\n{}\n\nnew table\n\n{}\n\n## Usage\n",
+                MARKER, MARKER
+            ))
+        );
+
+        // replacing twice is stable.
+        let once = replace_section(&contents, "new table\n").unwrap();
+        assert_eq!(replace_section(&once, "new table\n").unwrap(), once);
+    }
+
+    #[test]
+    fn test_replace_section_without_markers() {
+        assert!(replace_section("# Title\n", "table").is_err());
+        assert!(replace_section(&format!("# Title\n{}\n", MARKER), "table").is_err());
+    }
+
+    #[test]
+    fn test_fill_title() {
+        let contents = "# 🎄 Advent of Code {year}\n\nSolutions for [Advent of Code](https://adventofcode.com/) in Rust.\n";
+        assert_eq!(
+            fill_title(contents, Some(2022)),
+            "# 🎄 Advent of Code 2022\n\nSolutions for [Advent of Code](https://adventofcode.com/) in Rust.\n"
+        );
+        assert_eq!(fill_title(contents, None), contents);
+    }
+}
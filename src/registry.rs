@@ -0,0 +1,79 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! All day solutions in `src/bin`, compiled into the library so they can be run in-process.
+//! The list of days is generated by `build.rs`.
+use std::time::Duration;
+
+use pico_args::Arguments;
+
+use crate::bench::{self, BenchResult};
+use crate::cli;
+use crate::examples;
+use crate::report::PartResult;
+use crate::solution::{self, Solution};
+
+/// A registered day solution.
+pub struct Day {
+    pub day: u8,
+    run_with: fn(&str, &mut dyn FnMut(PartResult)),
+    bench: fn(&str, Duration) -> Vec<BenchResult>,
+    cli: fn(Arguments) -> cli::Result,
+    test: fn() -> Result<usize, String>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            run_with: run_with::<S>,
+            bench: bench::bench::<S>,
+            cli: solution::run_cli::<S>,
+            test: examples::verify::<S>,
+        }
+    }
+
+    /// Runs both parts against `input`.
+    pub fn run(&self, input: &str) -> Vec<PartResult> {
+        let mut results = vec![];
+        self.run_with(input, &mut |result| results.push(result));
+        results
+    }
+
+    /// Runs both parts against `input`, handing each result to `on_result` as soon as it is available.
+    pub fn run_with(&self, input: &str, on_result: &mut dyn FnMut(PartResult)) {
+        (self.run_with)(input, on_result)
+    }
+
+    /// Benchmarks both parts against `input`, spending up to `budget` on each.
+    pub fn bench(&self, input: &str, budget: Duration) -> Vec<BenchResult> {
+        (self.bench)(input, budget)
+    }
+
+    /// Runs the day the way its binary does, with the options of `solution::run_cli`.
+    pub fn run_cli(&self, args: Arguments) -> cli::Result {
+        (self.cli)(args)
+    }
+
+    /// Checks the day against the expected answers of its examples, see `examples::verify`.
+    pub fn test(&self) -> Result<usize, String> {
+        (self.test)()
+    }
+}
+
+fn run_with<S: Solution>(input: &str, on_result: &mut dyn FnMut(PartResult)) {
+    solution::run_with::<S>(input, on_result)
+}
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// All registered solutions, ordered by day.
+pub fn all() -> &'static [Day] {
+    DAYS
+}
+
+/// Returns the registered solution for `day`, if one exists.
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
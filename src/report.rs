@@ -0,0 +1,240 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+use tinyjson::JsonValue;
+
+use crate::answers::Verdict;
+use crate::memory::MemoryStats;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+
+/// Environment variable that switches the day binaries to machine-readable output.
+pub const JSON_ENV_VAR: &str = "AOC_JSON";
+
+/// Outcome of running a single part of a day's solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Error returned by the solver.
+    pub error: Option<String>,
+    /// Time spent solving the part, excluding parsing the input.
+    pub elapsed: Duration,
+    /// Time spent parsing the input. Parsing is shared by both parts, so only the first part carries it.
+    pub parse_elapsed: Option<Duration>,
+    /// Allocations made while solving the part, recorded with the `memory` feature.
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartResult {
+    /// A part that was solved with `answer` in `elapsed`, or that is not solved yet if `answer` is `None`.
+    pub fn new(day: u8, part: u8, answer: Option<String>, elapsed: Duration) -> PartResult {
+        PartResult {
+            day,
+            part,
+            answer,
+            error: None,
+            elapsed,
+            parse_elapsed: None,
+            memory: None,
+        }
+    }
+
+    /// A part that failed with `error` before producing an answer.
+    pub fn failed(day: u8, part: u8, error: String) -> PartResult {
+        PartResult {
+            error: Some(error),
+            ..PartResult::new(day, part, None, Duration::ZERO)
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Serializes the result as a single JSON line.
+    pub fn to_json(&self) -> String {
+        let string = |val: &Option<String>| match val {
+            Some(val) => JsonValue::String(val.clone()).stringify().unwrap(),
+            None => "null".into(),
+        };
+
+        let number = |val: Option<u128>| val.map_or("null".into(), |val| val.to_string());
+        let memory =
+            |field: fn(&MemoryStats) -> u64| number(self.memory.as_ref().map(|m| field(m) as u128));
+
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"elapsed_ns":{},"solved":{},"error":{},"parse_ns":{},"allocations":{},"allocated_bytes":{},"peak_bytes":{}}}"#,
+            self.day,
+            self.part,
+            string(&self.answer),
+            self.elapsed.as_nanos(),
+            self.is_solved(),
+            string(&self.error),
+            number(self.parse_elapsed.map(|elapsed| elapsed.as_nanos())),
+            memory(|m| m.allocations),
+            memory(|m| m.allocated_bytes),
+            memory(|m| m.peak_bytes)
+        )
+    }
+
+    /// Parses a line emitted by `to_json`. Returns `None` for any other line.
+    pub fn from_json(line: &str) -> Option<PartResult> {
+        let value: JsonValue = line.trim().parse().ok()?;
+        let object: &HashMap<String, JsonValue> = value.get()?;
+
+        let number = |key: &str| -> Option<f64> { object.get(key)?.get::<f64>().copied() };
+
+        let string = |key: &str| -> Option<Option<String>> {
+            match object.get(key) {
+                Some(JsonValue::String(val)) => Some(Some(val.clone())),
+                Some(JsonValue::Null) | None => Some(None),
+                _ => None,
+            }
+        };
+
+        if !object.contains_key("answer") {
+            return None;
+        }
+
+        Some(PartResult {
+            day: number("day")? as u8,
+            part: number("part")? as u8,
+            answer: string("answer")?,
+            error: string("error")?,
+            elapsed: Duration::from_nanos(number("elapsed_ns")? as u64),
+            parse_elapsed: number("parse_ns").map(|ns| Duration::from_nanos(ns as u64)),
+            memory: match (
+                number("allocations"),
+                number("allocated_bytes"),
+                number("peak_bytes"),
+            ) {
+                (Some(allocations), Some(allocated_bytes), Some(peak_bytes)) => Some(MemoryStats {
+                    allocations: allocations as u64,
+                    allocated_bytes: allocated_bytes as u64,
+                    peak_bytes: peak_bytes as u64,
+                }),
+                _ => None,
+            },
+        })
+    }
+
+    /// Renders the result the way a day binary prints it to a terminal, marked with its verdict.
+    /// The parse time is rendered in front of the part that carries it.
+    pub fn to_human(&self, verdict: &Verdict) -> String {
+        let header = match self.parse_elapsed {
+            Some(elapsed) => format!(
+                "🎄 {}Parse{} 🎄\n{}(elapsed: {:.2?}){}\n",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, elapsed, ANSI_RESET
+            ),
+            None => String::new(),
+        };
+        let header = format!(
+            "{}🎄 {}Part {}{} 🎄",
+            header, ANSI_BOLD, self.part, ANSI_RESET
+        );
+        if let Some(error) = &self.error {
+            return format!(
+                "{}\n{}Part {} failed: {}{}{}",
+                header,
+                ANSI_RED,
+                self.part,
+                error,
+                ANSI_RESET,
+                verdict.mark()
+            );
+        }
+
+        match &self.answer {
+            Some(answer) => format!(
+                "{}\n{}{} {}(elapsed: {:.2?}{}){}",
+                header,
+                answer,
+                verdict.mark(),
+                ANSI_ITALIC,
+                self.elapsed,
+                self.memory
+                    .map_or(String::new(), |memory| format!(", {}", memory.to_human())),
+                ANSI_RESET
+            ),
+            None => format!("{}\nnot solved.{}", header, verdict.mark()),
+        }
+    }
+}
+
+/// Whether `AOC_JSON=1` asks for results as JSON lines. Day binaries also accept a `--json` flag.
+pub fn json_output_enabled() -> bool {
+    matches!(env::var(JSON_ENV_VAR), Ok(val) if !val.is_empty() && val != "0")
+}
+
+/// Prints the result as a JSON line if `json` is set, formatted for a terminal otherwise.
+pub fn print_result(result: &PartResult, verdict: &Verdict, json: bool) {
+    if json {
+        println!("{}", result.to_json());
+    } else {
+        println!("{}", result.to_human(verdict));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_roundtrip() {
+        let result = PartResult {
+            day: 10,
+            part: 2,
+            answer: Some("##..\n\"quoted\"".into()),
+            error: None,
+            elapsed: Duration::from_nanos(74),
+            parse_elapsed: Some(Duration::from_nanos(1200)),
+            memory: Some(MemoryStats {
+                allocations: 3,
+                allocated_bytes: 4096,
+                peak_bytes: 2048,
+            }),
+        };
+
+        let line = result.to_json();
+        assert!(!line.contains('\n'));
+        assert_eq!(PartResult::from_json(&line), Some(result));
+    }
+
+    #[test]
+    fn test_json_unsolved() {
+        let result = PartResult::new(1, 1, None, Duration::from_micros(755));
+
+        assert_eq!(
+            result.to_json(),
+            r#"{"day":1,"part":1,"answer":null,"elapsed_ns":755000,"solved":false,"error":null,"parse_ns":null,"allocations":null,"allocated_bytes":null,"peak_bytes":null}"#
+        );
+        assert_eq!(PartResult::from_json(&result.to_json()), Some(result));
+    }
+
+    #[test]
+    fn test_json_error() {
+        let result = PartResult {
+            elapsed: Duration::from_nanos(12),
+            ..PartResult::failed(5, 2, "invalid instruction \"move x\"".into())
+        };
+
+        assert!(!result.is_solved());
+        assert!(result.is_failed());
+        assert_eq!(PartResult::from_json(&result.to_json()), Some(result));
+    }
+
+    #[test]
+    fn test_from_json_ignores_other_lines() {
+        assert_eq!(PartResult::from_json("🎄 Part 1 🎄"), None);
+        assert_eq!(PartResult::from_json(r#"{"day":1}"#), None);
+    }
+}
@@ -0,0 +1,321 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use pico_args::Arguments;
+
+use crate::answers::{self, Answers, Verdict};
+use crate::bench;
+use crate::cli;
+use crate::memory;
+use crate::panics;
+use crate::report::{self, PartResult};
+use crate::submit;
+
+/// A day's puzzle solution.
+///
+/// `parse` turns the raw puzzle input into the value both parts operate on.
+/// Solutions that work on the raw input directly can use `type Input<'a> = &'a str`.
+///
+/// `PartOne` and `PartTwo` are the return types of the parts, either `Option<T>` or `Result<T, E>`
+/// for any `T` and `E` that implement `Display`.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+    type PartOne: IntoOutcome;
+    type PartTwo: IntoOutcome;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne;
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo;
+}
+
+/// Return value of a solver: `Ok(Some(answer))`, `Ok(None)` if the part is not solved yet, or an error message.
+pub type Outcome = Result<Option<String>, String>;
+
+/// Converts the return value of a solver into an `Outcome`.
+pub trait IntoOutcome {
+    fn into_outcome(self) -> Outcome;
+}
+
+impl<T: Display> IntoOutcome for Option<T> {
+    fn into_outcome(self) -> Outcome {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Display, E: Display> IntoOutcome for Result<T, E> {
+    fn into_outcome(self) -> Outcome {
+        self.map(|answer| Some(answer.to_string()))
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Times a single solver call and records its answer or error. A panic of the solver is recorded as an error.
+/// With the `memory` feature, the allocations of the call are recorded as well.
+pub fn run_part<I: ?Sized, R: IntoOutcome>(
+    day: u8,
+    part: u8,
+    solver: impl FnOnce(&I) -> R,
+    input: &I,
+) -> PartResult {
+    let timer = Instant::now();
+    let (result, memory) = memory::measure(|| panics::catch(|| solver(input)));
+    let elapsed = timer.elapsed();
+
+    let (answer, error) = match result.and_then(IntoOutcome::into_outcome) {
+        Ok(answer) => (answer, None),
+        Err(e) => (None, Some(e)),
+    };
+
+    PartResult {
+        error,
+        memory,
+        ..PartResult::new(day, part, answer, elapsed)
+    }
+}
+
+/// Runs both parts of a solution, handing each result to `on_result` as soon as it is available.
+/// The input is parsed once and timed separately; the first part carries the parse time.
+/// If parsing the input panics, both parts are reported as failed.
+pub fn run_with<S: Solution>(input: &str, mut on_result: impl FnMut(PartResult)) {
+    let timer = Instant::now();
+    let parsed = panics::catch(|| S::parse(input));
+    let parse_elapsed = Some(timer.elapsed());
+
+    match parsed {
+        Ok(parsed) => {
+            on_result(PartResult {
+                parse_elapsed,
+                ..run_part(S::DAY, 1, S::part_one, &parsed)
+            });
+            on_result(run_part(S::DAY, 2, S::part_two, &parsed));
+        }
+        Err(e) => {
+            for part in 1..=2 {
+                on_result(PartResult {
+                    parse_elapsed: parse_elapsed.filter(|_| part == 1),
+                    ..PartResult::failed(S::DAY, part, format!("parse {}", e))
+                });
+            }
+        }
+    }
+}
+
+/// Runs both parts of a solution and collects the results.
+pub fn run<S: Solution>(input: &str) -> Vec<PartResult> {
+    let mut results = vec![];
+    run_with::<S>(input, |result| results.push(result));
+    results
+}
+
+/// Entry point of a day binary, see `run_cli`.
+pub fn run_main<S: Solution>() {
+    if let Err(e) = crate::config::load() {
+        cli::exit(Err(e.into()));
+    }
+
+    cli::exit(run_cli::<S>(Arguments::from_env()))
+}
+
+/// Reads the day's input and prints the results of both parts. Used by the day binaries and `cargo aoc solve`.
+/// With `--bench [--bench-time <ms>]`, each part is benchmarked instead of run once.
+/// With `--json` or `AOC_JSON=1`, each part is printed as a JSON line.
+/// The input can be read from a different file with `--input <path>` or `AOC_INPUT`, or from stdin with `-`.
+/// Answers are checked against the accepted answers; the command fails on mismatch.
+/// With `--lock`, the answers of solved parts without an accepted answer are accepted.
+/// A part that returns an error or panics is reported without stopping the other part, but also fails the command.
+pub fn run_cli<S: Solution>(mut args: Arguments) -> cli::Result {
+    let bench = args.contains("--bench");
+    let lock = args.contains("--lock");
+    let json = args.contains("--json") || report::json_output_enabled();
+    let budget = args
+        .opt_value_from_str::<_, u64>("--bench-time")?
+        .or(crate::config::get().runner.bench_time)
+        .map_or(bench::DEFAULT_BUDGET, Duration::from_millis);
+    let input_override: Option<String> = args.opt_value_from_str("--input")?;
+    cli::finish(args)?;
+
+    let input = crate::read_input(S::DAY, input_override.as_deref())?;
+
+    let answers_path = answers::answers_path();
+    let mut answers =
+        Answers::load(&answers_path).map_err(|e| format!("Failed to load answers: {}", e))?;
+
+    let mut results = vec![];
+    let mut verdicts = vec![];
+    let mut failed = false;
+
+    if bench {
+        for result in bench::bench::<S>(&input, budget) {
+            let verdict = answers.check_answer(result.day, result.part, result.answer.as_deref());
+            println!("{}", result.to_human(&verdict));
+            failed |= result.error.is_some();
+            verdicts.push(verdict);
+        }
+    } else {
+        run_with::<S>(&input, |result| {
+            let verdict = answers.check(&result);
+            report::print_result(&result, &verdict, json);
+            if verdict != Verdict::Correct {
+                submit::warn_guess(&result);
+            }
+            failed |= result.is_failed();
+            verdicts.push(verdict);
+            results.push(result);
+        });
+    }
+
+    if lock {
+        let locked = answers.lock(&results);
+        answers
+            .save(&answers_path)
+            .map_err(|e| format!("Failed to save answers: {}", e))?;
+        eprintln!(
+            "Locked {} answer(s) in \"{}\".",
+            locked,
+            answers_path.display()
+        );
+    }
+
+    // a differing accepted answer is never replaced, not even with `--lock`.
+    if verdicts.iter().any(|v| matches!(v, Verdict::Incorrect(_))) {
+        if lock {
+            eprintln!(
+                "Accepted answers are never replaced. Remove them from \"{}\" to accept the new answers.",
+                answers_path.display()
+            );
+        }
+        return Err(cli::Error::Failed);
+    }
+
+    if failed {
+        return Err(cli::Error::Failed);
+    }
+
+    Ok(())
+}
+
+/// Generates the `main` function of a day binary.
+#[macro_export]
+macro_rules! main {
+    ($solution:ty) => {
+        // day modules are compiled into the library's registry as well, where `main` is unused.
+        #[allow(dead_code)]
+        fn main() {
+            advent_of_code::solution::run_main::<$solution>();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u8 = 3;
+
+        type Input<'a> = Vec<u32>;
+        type PartOne = Option<u32>;
+        type PartTwo = Option<String>;
+
+        fn parse(input: &str) -> Vec<u32> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_one(input: &Vec<u32>) -> Option<u32> {
+            Some(input.iter().sum())
+        }
+
+        fn part_two(_input: &Vec<u32>) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let results = run::<Example>("1\n2\n3");
+
+        assert_eq!(results.len(), 2);
+        assert_eq!((results[0].day, results[0].part), (3, 1));
+        assert_eq!(results[0].answer, Some("6".into()));
+        assert!(results[0].parse_elapsed.is_some());
+        assert!(results[1].parse_elapsed.is_none());
+        assert_eq!((results[1].day, results[1].part), (3, 2));
+        assert_eq!(results[1].answer, None);
+    }
+
+    #[test]
+    fn test_bench_parse() {
+        let results = bench::bench::<Example>("1\n2\n3", Duration::ZERO);
+
+        assert_eq!(results[0].answer, Some("6".into()));
+        assert_eq!(
+            results[0].parse.as_ref().map(|stats| stats.samples),
+            Some(1)
+        );
+        assert!(results[1].parse.is_none());
+    }
+
+    fn cli_args(args: &[&str]) -> Arguments {
+        Arguments::from_vec(args.iter().map(|arg| arg.into()).collect())
+    }
+
+    #[test]
+    fn test_run_cli_json() {
+        let input = std::env::temp_dir().join(format!("aoc-run-cli-{}.txt", std::process::id()));
+        std::fs::write(&input, "1\n2\n3").unwrap();
+        let input = input.to_str().unwrap();
+
+        assert_eq!(
+            run_cli::<Example>(cli_args(&["--json", "--input", input])),
+            Ok(())
+        );
+        assert!(matches!(
+            run_cli::<Example>(cli_args(&["--jsn", "--input", input])),
+            Err(cli::Error::Args(_))
+        ));
+
+        std::fs::remove_file(input).unwrap();
+    }
+
+    #[test]
+    fn test_run_part_error() {
+        let parse = |input: &str| input.parse::<u32>().map(|n| n * 2);
+
+        let result = run_part(3, 1, parse, "21");
+        assert_eq!((result.answer, result.error), (Some("42".into()), None));
+
+        let result = run_part(3, 1, parse, "x");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.error, Some("invalid digit found in string".into()));
+    }
+
+    #[test]
+    fn test_run_part_panic() {
+        let result = run_part(6, 1, |input: &str| Some(input.as_bytes()[4]), "abc");
+
+        assert!(result.is_failed());
+        let error = result.error.unwrap();
+        assert!(
+            error.starts_with("panicked at src/solution.rs:"),
+            "{}",
+            error
+        );
+        assert!(error.contains("index out of bounds"), "{}", error);
+
+        let results = run::<Example>("1\nx");
+        assert!(results.iter().all(|result| result.is_failed()));
+        assert!(results[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .starts_with("parse panicked at"));
+    }
+}
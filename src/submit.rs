@@ -0,0 +1,347 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Answer submissions and the log of their feedback, stored in `submissions.jsonl`.
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tinyjson::JsonValue;
+
+use crate::history::utc_timestamp;
+use crate::report::PartResult;
+
+pub const SUBMISSIONS_FILE: &str = "submissions.jsonl";
+
+/// Location of the submission log in the project directory.
+pub fn submissions_path() -> PathBuf {
+    crate::project_dir().join(SUBMISSIONS_FILE)
+}
+
+/// Environment variable that replaces the `aoc` command used to submit answers.
+pub const COMMAND_ENV_VAR: &str = "AOC_COMMAND";
+
+pub const DEFAULT_COMMAND: &str = "aoc";
+
+/// How the Advent of Code website responded to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// Answered too recently, the answer was not checked.
+    RateLimited,
+    /// The part is locked or already solved.
+    WrongLevel,
+    /// The response could not be recognized.
+    Unknown,
+}
+
+impl Feedback {
+    /// Recognizes the feedback in the response text printed by the website or aoc-cli.
+    pub fn parse(response: &str) -> Feedback {
+        let response = response.to_lowercase();
+
+        if response.contains("that's the right answer") {
+            Feedback::Correct
+        } else if response.contains("answer is too high") {
+            Feedback::TooHigh
+        } else if response.contains("answer is too low") {
+            Feedback::TooLow
+        } else if response.contains("that's not the right answer") {
+            Feedback::Incorrect
+        } else if response.contains("gave an answer too recently") {
+            Feedback::RateLimited
+        } else if response.contains("don't seem to be solving the right level") {
+            Feedback::WrongLevel
+        } else {
+            Feedback::Unknown
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Feedback::Correct => "correct",
+            Feedback::TooHigh => "too_high",
+            Feedback::TooLow => "too_low",
+            Feedback::Incorrect => "incorrect",
+            Feedback::RateLimited => "rate_limited",
+            Feedback::WrongLevel => "wrong_level",
+            Feedback::Unknown => "unknown",
+        }
+    }
+
+    pub fn from_name(val: &str) -> Option<Feedback> {
+        [
+            Feedback::Correct,
+            Feedback::TooHigh,
+            Feedback::TooLow,
+            Feedback::Incorrect,
+            Feedback::RateLimited,
+            Feedback::WrongLevel,
+            Feedback::Unknown,
+        ]
+        .into_iter()
+        .find(|feedback| feedback.as_str() == val)
+    }
+
+    /// Human-readable description of the feedback.
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Feedback::Correct => "That's the right answer!",
+            Feedback::TooHigh => "That's not the right answer, it is too high.",
+            Feedback::TooLow => "That's not the right answer, it is too low.",
+            Feedback::Incorrect => "That's not the right answer.",
+            Feedback::RateLimited => "You gave an answer too recently, try again later.",
+            Feedback::WrongLevel => "This part is already solved or not unlocked yet.",
+            Feedback::Unknown => "Could not recognize the response.",
+        }
+    }
+}
+
+/// A submitted answer and the feedback it received.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub feedback: Feedback,
+    pub date: String,
+}
+
+impl Submission {
+    pub fn new(day: u8, part: u8, answer: &str, feedback: Feedback) -> Submission {
+        Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            feedback,
+            date: utc_timestamp(SystemTime::now()),
+        }
+    }
+
+    /// Serializes the submission as a single JSON line.
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"feedback":"{}","date":{}}}"#,
+            self.day,
+            self.part,
+            JsonValue::String(self.answer.clone()).stringify().unwrap(),
+            self.feedback.as_str(),
+            JsonValue::String(self.date.clone()).stringify().unwrap()
+        )
+    }
+
+    pub fn from_json(line: &str) -> Option<Submission> {
+        let value: JsonValue = line.trim().parse().ok()?;
+        let object: &HashMap<String, JsonValue> = value.get()?;
+
+        let number = |key: &str| -> Option<f64> { object.get(key)?.get::<f64>().copied() };
+        let string = |key: &str| -> Option<&String> { object.get(key)?.get::<String>() };
+
+        Some(Submission {
+            day: number("day")? as u8,
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            feedback: Feedback::from_name(string("feedback")?)?,
+            date: string("date")?.clone(),
+        })
+    }
+}
+
+/// Loads all logged submissions from `path`. A missing file yields an empty log.
+pub fn load_log(path: &Path) -> Result<Vec<Submission>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                Submission::from_json(line)
+                    .ok_or_else(|| format!("invalid line in \"{}\": {}", path.display(), line))
+            })
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(format!("could not read \"{}\": {}", path.display(), e)),
+    }
+}
+
+/// Appends a submission to the log at `path`.
+pub fn append_log(path: &Path, submission: &Submission) -> Result<(), String> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", submission.to_json()))
+        .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
+}
+
+/// What earlier submissions of a part tell about its answer.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Guesses {
+    /// Answers that were submitted and are wrong.
+    pub wrong: Vec<String>,
+    /// Highest answer that was too low.
+    pub too_low: Option<i128>,
+    /// Lowest answer that was too high.
+    pub too_high: Option<i128>,
+}
+
+impl Guesses {
+    pub fn from_log(log: &[Submission], day: u8, part: u8) -> Guesses {
+        let mut guesses = Guesses::default();
+
+        for submission in log.iter().filter(|s| s.day == day && s.part == part) {
+            let value = submission.answer.trim().parse::<i128>().ok();
+
+            match submission.feedback {
+                Feedback::TooLow | Feedback::TooHigh | Feedback::Incorrect => {
+                    guesses.wrong.push(submission.answer.clone())
+                }
+                _ => continue,
+            }
+
+            match (submission.feedback, value) {
+                (Feedback::TooLow, Some(value)) => {
+                    guesses.too_low = guesses.too_low.max(Some(value))
+                }
+                (Feedback::TooHigh, Some(value)) => {
+                    guesses.too_high = Some(guesses.too_high.map_or(value, |h| h.min(value)))
+                }
+                _ => {}
+            }
+        }
+
+        guesses
+    }
+
+    /// Checks an answer against the earlier feedback.
+    /// Fails if the answer is known to be wrong or falls outside the established bounds.
+    pub fn check(&self, answer: &str) -> Result<(), String> {
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Err(format!("{} was already submitted and is wrong.", answer));
+        }
+
+        if let Ok(value) = answer.trim().parse::<i128>() {
+            match (self.too_low, self.too_high) {
+                (Some(low), _) if value <= low => {
+                    return Err(format!("{} is too low, {} already was.", answer, low))
+                }
+                (_, Some(high)) if value >= high => {
+                    return Err(format!("{} is too high, {} already was.", answer, high))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints a warning if the answer of a result is ruled out by earlier submissions.
+/// Problems with reading the submission log are ignored.
+pub fn warn_guess(result: &PartResult) {
+    let answer = match &result.answer {
+        Some(answer) => answer,
+        None => return,
+    };
+
+    if let Ok(log) = load_log(&submissions_path()) {
+        if let Err(e) = Guesses::from_log(&log, result.day, result.part).check(answer) {
+            eprintln!("⚠️  Part {}: {}", result.part, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_feedback() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Feedback::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Feedback::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Feedback::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Feedback::Incorrect,
+            ),
+            (
+                "You gave an answer too recently. You have 42s left to wait.",
+                Feedback::RateLimited,
+            ),
+            (
+                "You don't seem to be solving the right level.",
+                Feedback::WrongLevel,
+            ),
+            ("error: failed to connect", Feedback::Unknown),
+        ];
+
+        for (response, feedback) in cases {
+            assert_eq!(Feedback::parse(response), feedback, "{}", response);
+        }
+    }
+
+    fn submission(part: u8, answer: &str, feedback: Feedback) -> Submission {
+        Submission {
+            day: 1,
+            part,
+            answer: answer.into(),
+            feedback,
+            date: "2022-12-01T06:00:00Z".into(),
+        }
+    }
+
+    #[test]
+    fn test_guesses() {
+        let log = vec![
+            submission(1, "100", Feedback::TooLow),
+            submission(1, "500", Feedback::TooHigh),
+            submission(1, "200", Feedback::TooLow),
+            submission(1, "300", Feedback::Incorrect),
+            submission(1, "400", Feedback::RateLimited),
+            submission(2, "1000", Feedback::TooHigh),
+        ];
+
+        let guesses = Guesses::from_log(&log, 1, 1);
+        assert_eq!(guesses.too_low, Some(200));
+        assert_eq!(guesses.too_high, Some(500));
+
+        assert!(guesses.check("250").is_ok());
+        assert!(guesses.check("400").is_ok());
+        assert!(guesses.check("300").is_err());
+        assert!(guesses.check("150").is_err());
+        assert!(guesses.check("200").is_err());
+        assert!(guesses.check("600").is_err());
+        assert!(guesses.check("ABC").is_ok());
+    }
+
+    #[test]
+    fn test_submission_roundtrip() {
+        let submission = Submission {
+            day: 10,
+            part: 2,
+            answer: "EHZFZHCZ".into(),
+            feedback: Feedback::TooLow,
+            date: "2022-12-10T06:00:00Z".into(),
+        };
+
+        let line = submission.to_json();
+        assert!(!line.contains('\n'));
+        assert_eq!(Submission::from_json(&line), Some(submission));
+        assert_eq!(Submission::from_json(r#"{"day":1}"#), None);
+    }
+}
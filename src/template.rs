@@ -0,0 +1,159 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Templates of the solution modules created by `cargo scaffold`.
+//!
+//! Templates are `.rs` files in the templates directory (`templates` by default, see `aoc.toml`).
+//! `cargo scaffold <day> --template <name>` uses `<name>.rs`. Without `--template`, `default.rs` is used
+//! if it exists and the built-in `MODULE_TEMPLATE` otherwise.
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+pub const MODULE_TEMPLATE: &str = r###"use advent_of_code::Solution;
+
+pub fn part_one(_input: &str) -> Option<u32> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<u32> {
+    None
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = {{day}};
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
+}
+
+advent_of_code::main!(Day{{day_padded}});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // checks the answers listed in `src/examples/{{day_padded}}.expected`.
+    advent_of_code::example_tests!(Day{{day_padded}});
+}
+"###;
+
+/// Values of the placeholders of a template.
+pub struct Placeholders {
+    /// `{{day}}`, e.g. `7`. `{{day_padded}}` is the day with a leading zero, e.g. `07`.
+    pub day: u8,
+    /// `{{year}}`, the year of the event.
+    pub year: i16,
+    /// `{{title}}`, the title of the puzzle if it was downloaded already, e.g. `Day 7: No Space Left On Device`,
+    /// or just `Day 7` otherwise.
+    pub title: String,
+}
+
+pub fn render(template: &str, values: &Placeholders) -> String {
+    template
+        .replace("{{day_padded}}", &format!("{:02}", values.day))
+        .replace("{{day}}", &values.day.to_string())
+        .replace("{{year}}", &values.year.to_string())
+        .replace("{{title}}", &values.title)
+}
+
+/// Names of the templates in `dir`, in alphabetical order.
+pub fn available(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+                .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    names.sort_unstable();
+    names
+}
+
+/// Reads the template `name` from `dir`. Without a name, `default.rs` or the built-in template is used.
+pub fn load(dir: &Path, name: Option<&str>) -> Result<String, String> {
+    let path = dir.join(format!("{}.rs", name.unwrap_or(DEFAULT_TEMPLATE)));
+
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => match name {
+            None => Ok(MODULE_TEMPLATE.into()),
+            Some(name) => {
+                let available = available(dir);
+                Err(format!(
+                    "template \"{}\" does not exist in \"{}\". Available templates: {}",
+                    name,
+                    dir.display(),
+                    if available.is_empty() {
+                        "none".into()
+                    } else {
+                        available.join(", ")
+                    }
+                ))
+            }
+        },
+        Err(e) => Err(format!("could not read \"{}\": {}", path.display(), e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let values = Placeholders {
+            day: 7,
+            year: 2022,
+            title: "Day 7: No Space Left On Device".into(),
+        };
+
+        assert_eq!(
+            render(
+                "//! {{title}} ({{year}})\nstruct Day{{day_padded}};\nconst DAY: u8 = {{day}};",
+                &values
+            ),
+            "//! Day 7: No Space Left On Device (2022)\nstruct Day07;\nconst DAY: u8 = 7;"
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = crate::project_dir().join("templates");
+
+        assert!(available(&dir).contains(&"grid".to_string()));
+        assert!(load(&dir, Some("grid"))
+            .unwrap()
+            .contains("Day{{day_padded}}"));
+
+        let err = load(&dir, Some("gird")).unwrap_err();
+        assert!(err.contains("Available templates: "), "{}", err);
+        assert!(err.contains("grid"), "{}", err);
+
+        // without a default template in the directory, the built-in one is used.
+        let missing = dir.join("missing");
+        assert_eq!(load(&missing, None), Ok(MODULE_TEMPLATE.to_string()));
+    }
+}
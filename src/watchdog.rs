@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Runs a day in a child process and kills it once it exceeds its time limits.
+//! The child reports its results as JSON lines, see `PartResult::to_json`.
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::report::PartResult;
+
+/// Time limits of a day run. A limit of `None` is not enforced.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Limits {
+    /// Limit for running both parts of a day, including parsing the input.
+    pub day: Option<Duration>,
+    /// Limit for a single part. Parsing the input counts towards the first part.
+    pub part: Option<Duration>,
+}
+
+impl Limits {
+    pub fn is_set(&self) -> bool {
+        self.day.is_some() || self.part.is_some()
+    }
+}
+
+/// Which limit a day exceeded.
+enum Exceeded {
+    Day(Duration),
+    Part(Duration),
+}
+
+impl Exceeded {
+    fn message(&self) -> String {
+        match self {
+            Exceeded::Day(limit) => format!("timed out after {:.2?} (day limit)", limit),
+            Exceeded::Part(limit) => format!("timed out after {:.2?} (part limit)", limit),
+        }
+    }
+}
+
+/// Runs `command` and collects the results it prints for `day`.
+/// If a limit is exceeded, the child is killed and the parts it did not report are marked as timed out.
+pub fn run(mut command: Command, day: u8, limits: &Limits) -> Result<Vec<PartResult>, String> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("could not start day {:02}: {}", day, e))?;
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let (tx, rx) = mpsc::channel();
+
+    // reads in a thread of its own, so the limits can be enforced while the child is silent.
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if let Some(result) = PartResult::from_json(&line) {
+                if tx.send(result).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let start = Instant::now();
+    let mut part_start = start;
+    let mut results = vec![];
+
+    let exceeded = loop {
+        let deadlines = [
+            limits
+                .day
+                .map(|limit| (start + limit, Exceeded::Day(limit))),
+            limits
+                .part
+                .map(|limit| (part_start + limit, Exceeded::Part(limit))),
+        ];
+
+        let next = deadlines
+            .into_iter()
+            .flatten()
+            .min_by_key(|(deadline, _)| *deadline);
+
+        let received = match &next {
+            Some((deadline, _)) => {
+                rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match received {
+            Ok(result) => {
+                results.push(result);
+                part_start = Instant::now();
+            }
+            Err(RecvTimeoutError::Disconnected) => break None,
+            Err(RecvTimeoutError::Timeout) => break next.map(|(_, exceeded)| exceeded),
+        }
+    };
+
+    if let Some(exceeded) = exceeded {
+        // the child may have exited in the meantime, in which case there is nothing to kill.
+        let _ = child.kill();
+
+        for part in 1..=2 {
+            if results.iter().all(|result| result.part != part) {
+                results.push(PartResult {
+                    elapsed: part_start.elapsed(),
+                    ..PartResult::failed(day, part, exceeded.message())
+                });
+            }
+        }
+    }
+
+    child
+        .wait()
+        .map_err(|e| format!("could not wait for day {:02}: {}", day, e))?;
+
+    Ok(results)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn test_run_completes() {
+        let line =
+            r#"{"day":10,"part":1,"answer":"13140","elapsed_ns":5,"solved":true,"error":null}"#;
+        let limits = Limits {
+            day: Some(Duration::from_secs(10)),
+            part: None,
+        };
+
+        let results = run(shell(&format!("echo '{}'", line)), 10, &limits).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].answer, Some("13140".into()));
+    }
+
+    #[test]
+    fn test_run_times_out() {
+        let line =
+            r#"{"day":10,"part":1,"answer":"13140","elapsed_ns":5,"solved":true,"error":null}"#;
+        let limits = Limits {
+            day: None,
+            part: Some(Duration::from_millis(200)),
+        };
+
+        let start = Instant::now();
+        let results = run(shell(&format!("echo '{}'; sleep 10", line)), 10, &limits).unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_solved());
+        assert_eq!(
+            results[1].error,
+            Some("timed out after 200.00ms (part limit)".into())
+        );
+    }
+}
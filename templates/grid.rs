@@ -0,0 +1,54 @@
+//! {{title}}
+//! https://adventofcode.com/{{year}}/day/{{day}}
+use advent_of_code::Solution;
+
+/// The puzzle input as rows of bytes, indexed as `grid[y][x]`.
+pub type Grid = Vec<Vec<u8>>;
+
+pub fn parse(input: &str) -> Grid {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.bytes().collect())
+        .collect()
+}
+
+pub fn part_one(_grid: &Grid) -> Option<u32> {
+    None
+}
+
+pub fn part_two(_grid: &Grid) -> Option<u32> {
+    None
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = {{day}};
+
+    type Input<'a> = Grid;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Grid {
+        parse(input)
+    }
+
+    fn part_one(grid: &Grid) -> Option<u32> {
+        part_one(grid)
+    }
+
+    fn part_two(grid: &Grid) -> Option<u32> {
+        part_two(grid)
+    }
+}
+
+advent_of_code::main!(Day{{day_padded}});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // checks the answers listed in `src/examples/{{day_padded}}.expected`.
+    advent_of_code::example_tests!(Day{{day_padded}});
+}
@@ -0,0 +1,72 @@
+//! {{title}}
+//! https://adventofcode.com/{{year}}/day/{{day}}
+use std::str::FromStr;
+
+use advent_of_code::Solution;
+
+/// A line of the puzzle input.
+pub struct Entry {
+    // allowed to be unused until a part reads it.
+    #[allow(dead_code)]
+    line: String,
+}
+
+impl FromStr for Entry {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Entry, String> {
+        Ok(Entry {
+            line: line.to_string(),
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Entry> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .unwrap_or_else(|e| panic!("invalid line \"{}\": {}", line, e))
+        })
+        .collect()
+}
+
+pub fn part_one(_entries: &[Entry]) -> Option<u32> {
+    None
+}
+
+pub fn part_two(_entries: &[Entry]) -> Option<u32> {
+    None
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = {{day}};
+
+    type Input<'a> = Vec<Entry>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Vec<Entry> {
+        parse(input)
+    }
+
+    fn part_one(entries: &Vec<Entry>) -> Option<u32> {
+        part_one(entries)
+    }
+
+    fn part_two(entries: &Vec<Entry>) -> Option<u32> {
+        part_two(entries)
+    }
+}
+
+advent_of_code::main!(Day{{day_padded}});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // checks the answers listed in `src/examples/{{day_padded}}.expected`.
+    advent_of_code::example_tests!(Day{{day_padded}});
+}
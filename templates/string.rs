@@ -0,0 +1,43 @@
+//! {{title}}
+//! https://adventofcode.com/{{year}}/day/{{day}}
+use advent_of_code::Solution;
+
+pub fn part_one(_input: &str) -> Option<String> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<String> {
+    None
+}
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const DAY: u8 = {{day}};
+
+    type Input<'a> = &'a str;
+    type PartOne = Option<String>;
+    type PartTwo = Option<String>;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<String> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<String> {
+        part_two(input)
+    }
+}
+
+advent_of_code::main!(Day{{day_padded}});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // checks the answers listed in `src/examples/{{day_padded}}.expected`.
+    advent_of_code::example_tests!(Day{{day_padded}});
+}